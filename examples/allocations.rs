@@ -0,0 +1,82 @@
+//! Counts the allocations `line()` makes for a range where few numbers match.
+//!
+//! Run with `cargo run --release --example allocations`.
+
+use extended_fizzbuzz::{line, line_into, Matcher};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+/// Wraps the system allocator and counts every allocation.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// How `line()` used to handle numbers nothing matched.
+fn naive_line(number: usize, matchers: &[Matcher]) -> String {
+    let mut out = String::new();
+    for m in matchers.iter().filter(|m| m.matches(number)) {
+        out += m.word();
+    }
+    if out.is_empty() {
+        out += &number.to_string();
+    }
+    out
+}
+
+fn measure(name: &str, mut f: impl FnMut(usize)) {
+    let numbers = 1_000_000_000..1_001_000_000;
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for i in numbers.clone() {
+        f(i);
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+    println!(
+        "{:<10} {:>10} allocations for {} lines in {:?}",
+        name,
+        allocations,
+        numbers.len(),
+        elapsed
+    );
+}
+
+fn main() {
+    // Only a handful of numbers match, so almost every line is the number itself.
+    let matchers = vec![Matcher::new(997, "Prime").unwrap()];
+
+    measure("naive", |i| {
+        std::hint::black_box(naive_line(i, &matchers));
+    });
+    measure("line", |i| {
+        std::hint::black_box(line(i, &matchers));
+    });
+
+    let mut buf = String::new();
+    measure("line_into", |i| {
+        line_into(i, &matchers, &mut buf);
+        std::hint::black_box(&buf);
+    });
+}
@@ -0,0 +1,21 @@
+//! Compares the serial and the parallel version of `fizzbuzz_lines()`.
+//!
+//! Run with `cargo run --release --example parallel --features rayon`.
+
+use extended_fizzbuzz::{fizzbuzz_lines, fizzbuzz_lines_par, Matcher};
+use std::time::Instant;
+
+fn main() {
+    let matchers = Matcher::classic();
+    let to = 10_000_000;
+
+    let start = Instant::now();
+    let serial = fizzbuzz_lines(1, to, &matchers).expect("FizzBuzzing failed");
+    println!("serial:   {:?}", start.elapsed());
+
+    let start = Instant::now();
+    let parallel = fizzbuzz_lines_par(1, to, &matchers).expect("FizzBuzzing failed");
+    println!("parallel: {:?}", start.elapsed());
+
+    assert_eq!(serial, parallel);
+}
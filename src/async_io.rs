@@ -0,0 +1,123 @@
+use crate::{line_into, FizzBuzzError, Matcher};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Provides a configurable version of FizzBuzz, that writes to an asynchronous writer.
+///
+/// This works the same way as `fizzbuzz_to()`, but uses `tokio::io`. Writing waits for the
+/// `writer` to accept more data, so slow destinations like TCP streams are never overwhelmed.
+///
+/// Every line is terminated by a `\n`. The output isn't buffered, so wrap the `writer` in a
+/// `tokio::io::BufWriter` if it issues a system call for every write.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output. It is flushed after the last line.
+///
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_to_async, Matcher};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut out = Vec::new();
+/// fizzbuzz_to_async(&mut out, 1, 5, &Matcher::classic()).await.unwrap();
+///
+/// assert_eq!(out, b"1\n2\nFizz\n4\nBuzz\n");
+/// # });
+/// ```
+pub async fn fizzbuzz_to_async<W>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<(), FizzBuzzError>
+where
+    W: AsyncWrite + Unpin,
+{
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let mut buf = String::new();
+
+    for i in from..=to {
+        line_into(i, matchers, &mut buf);
+        buf.push('\n');
+        writer
+            .write_all(buf.as_bytes())
+            .await
+            .map_err(FizzBuzzError::io_at(i))?;
+    }
+    writer.flush().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::Future;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn async_normal() {
+        let mut out = Vec::new();
+        block_on(fizzbuzz_to_async(&mut out, 1, 15, &Matcher::classic())).unwrap();
+
+        let mut expected = Vec::new();
+        crate::fizzbuzz_to(&mut expected, 1, 15, &Matcher::classic()).unwrap();
+
+        assert_eq!(out, expected);
+        assert!(out.ends_with(b"14\nFizzBuzz\n"));
+    }
+
+    #[test]
+    fn async_from_bigger_than_to() {
+        let mut out = Vec::new();
+
+        assert!(matches!(
+            block_on(fizzbuzz_to_async(&mut out, 2, 1, &Matcher::classic())),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn async_backpressure() {
+        // A duplex stream only buffers 8 bytes, so the writer has to wait for the reader.
+        let (mut writer, mut reader) = tokio::io::duplex(8);
+
+        let out = block_on(async {
+            let write = async {
+                fizzbuzz_to_async(&mut writer, 1, 100, &Matcher::classic()).await?;
+                drop(writer);
+                Ok::<_, FizzBuzzError>(())
+            };
+            let read = async {
+                let mut out = Vec::new();
+                tokio::io::AsyncReadExt::read_to_end(&mut reader, &mut out).await?;
+                Ok::<_, std::io::Error>(out)
+            };
+
+            let (written, read) = tokio::join!(write, read);
+            written.unwrap();
+            read.unwrap()
+        });
+
+        let expected: String = (1..=100)
+            .map(|i| format!("{}\n", crate::line(i, &Matcher::classic())))
+            .collect();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+}
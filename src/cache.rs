@@ -0,0 +1,167 @@
+use crate::{fallback_string, LineResult, Matcher};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A cache for the results of a set of matchers for the most recently checked number.
+///
+/// Checking the same number with `line()`, `classify()` and `matching_indices()` runs every
+/// matcher again for each of them. A `MatchCache` runs the matchers only once per number, and
+/// answers all following queries for the same number from the cache. This is mostly useful for
+/// expensive matchers, like the ones created by `Matcher::prime()` or `Matcher::from_fn()`.
+///
+/// Only the results for a single number are kept. Checking a different number replaces them.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{LineResult, MatchCache, Matcher};
+///
+/// let matchers = Matcher::classic();
+/// let mut cache = MatchCache::new(&matchers);
+///
+/// assert_eq!(cache.line(15), "FizzBuzz");
+/// assert_eq!(cache.matching_indices(15), vec![0, 1]);
+/// assert_eq!(cache.classify(15), LineResult::Matched(vec!["Fizz", "Buzz"]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MatchCache<'a> {
+    matchers: &'a [Matcher],
+    number: Option<usize>,
+    results: Vec<bool>,
+}
+
+impl<'a> MatchCache<'a> {
+    /// Create a new, empty cache for `matchers`.
+    pub fn new(matchers: &'a [Matcher]) -> Self {
+        MatchCache {
+            matchers,
+            number: None,
+            results: Vec::with_capacity(matchers.len()),
+        }
+    }
+
+    /// Get the matchers the cache belongs to.
+    pub fn matchers(&self) -> &'a [Matcher] {
+        self.matchers
+    }
+
+    /// Check which matchers match `number`.
+    ///
+    /// The result contains one value per matcher, in the order of the matchers. The matchers are
+    /// only run, if `number` isn't the number checked last.
+    pub fn matches(&mut self, number: usize) -> &[bool] {
+        if self.number != Some(number) {
+            self.results.clear();
+            self.results
+                .extend(self.matchers.iter().map(|m| m.matches(number)));
+            self.number = Some(number);
+        }
+
+        &self.results
+    }
+
+    /// Get the result of `line()` for `number`, using the cache.
+    pub fn line(&mut self, number: usize) -> String {
+        let mut out = String::new();
+        let mut matched = false;
+
+        for (m, _) in self.matching(number) {
+            out.push_str(m.word());
+            matched = true;
+        }
+
+        if matched {
+            out
+        } else {
+            fallback_string(number)
+        }
+    }
+
+    /// Get the result of `classify()` for `number`, using the cache.
+    pub fn classify(&mut self, number: usize) -> LineResult<'a> {
+        let words: Vec<_> = self.matching(number).map(|(m, _)| m.word()).collect();
+
+        if words.is_empty() {
+            LineResult::Number(number)
+        } else {
+            LineResult::Matched(words)
+        }
+    }
+
+    /// Get the result of `matching_indices()` for `number`, using the cache.
+    pub fn matching_indices(&mut self, number: usize) -> Vec<usize> {
+        self.matching(number).map(|(_, i)| i).collect()
+    }
+
+    /// Get the matchers matching `number`, together with their positions.
+    fn matching(&mut self, number: usize) -> impl Iterator<Item = (&'a Matcher, usize)> + '_ {
+        let matchers = self.matchers;
+
+        self.matches(number)
+            .iter()
+            .enumerate()
+            .filter(|(_, matched)| **matched)
+            .map(move |(i, _)| (&matchers[i], i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{classify, line, matching_indices};
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn cache_consistent() {
+        let matchers = vec![
+            Matcher::fizz(),
+            Matcher::buzz(),
+            Matcher::prime("Prime"),
+            Matcher::exactly(7, "Seven"),
+        ];
+        let mut cache = MatchCache::new(&matchers);
+
+        for i in (0..200).chain([15, 15, 7, 7, 3].iter().cloned()) {
+            assert_eq!(cache.line(i), line(i, &matchers));
+            assert_eq!(cache.classify(i), classify(i, &matchers));
+            assert_eq!(cache.matching_indices(i), matching_indices(i, &matchers));
+        }
+    }
+
+    #[test]
+    fn cache_short_circuits() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let matchers = vec![
+            Matcher::fizz(),
+            Matcher::from_fn("Even", move |n| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                n % 2 == 0
+            }),
+        ];
+        let mut cache = MatchCache::new(&matchers);
+
+        assert_eq!(cache.line(6), "FizzEven");
+        assert_eq!(cache.classify(6), LineResult::Matched(vec!["Fizz", "Even"]));
+        assert_eq!(cache.matching_indices(6), vec![0, 1]);
+        assert_eq!(cache.matches(6), &[true, true]);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert_eq!(cache.line(7), "7");
+        assert_eq!(cache.line(7), "7");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Only the most recent number is kept.
+        assert_eq!(cache.line(6), "FizzEven");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn cache_empty() {
+        let mut cache = MatchCache::new(&[]);
+
+        assert_eq!(cache.line(15), "15");
+        assert!(cache.matches(15).is_empty());
+        assert_eq!(cache.classify(15), LineResult::Number(15));
+    }
+}
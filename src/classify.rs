@@ -0,0 +1,234 @@
+use crate::Matcher;
+#[cfg(feature = "std")]
+use crate::{with_stdout, FizzBuzzError};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// The result of FizzBuzz for a single number, as structured data.
+///
+/// Created by the `classify()` function.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LineResult<'a> {
+    /// At least one matcher matched the number. Contains the words of all matching matchers, in
+    /// the order of the matchers.
+    Matched(Vec<&'a str>),
+    /// No matcher matched the number. Contains the number itself.
+    Number(usize),
+}
+
+/// The lines `fizzbuzz_filtered()` prints.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Filter {
+    /// Only print the lines of numbers at least one matcher matches.
+    MatchedOnly,
+    /// Only print the lines of numbers no matcher matches.
+    UnmatchedOnly,
+    /// Print all lines, just like `fizzbuzz()`.
+    #[default]
+    All,
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that returns structured data.
+///
+/// Other than `line()`, this doesn't join the words into a single string, so the result can be
+/// rendered in different ways.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{classify, LineResult, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(classify(7, &matchers), LineResult::Number(7));
+/// assert_eq!(classify(9, &matchers), LineResult::Matched(vec!["Fizz"]));
+/// assert_eq!(classify(15, &matchers), LineResult::Matched(vec!["Fizz", "Buzz"]));
+/// ```
+pub fn classify(number: usize, matchers: &[Matcher]) -> LineResult<'_> {
+    let words: Vec<_> = matchers
+        .iter()
+        .filter(|m| m.matches(number))
+        .map(|m| m.word())
+        .collect();
+
+    if words.is_empty() {
+        LineResult::Number(number)
+    } else {
+        LineResult::Matched(words)
+    }
+}
+
+/// Provides a configurable version of FizzBuzz, that only prints some of the lines.
+///
+/// The output is written to stdout. Use `fizzbuzz_filtered_to()` to write it somewhere else.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// With `which` you can choose wether only substituted numbers, only unsubstituted numbers, or
+/// all numbers are printed.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_filtered, Filter, Matcher};
+///
+/// assert!(fizzbuzz_filtered(1, 15, &Matcher::classic(), Filter::MatchedOnly).is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_filtered(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    which: Filter,
+) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_filtered_to(out, from, to, matchers, which))
+}
+
+/// Provides a configurable version of FizzBuzz, that only writes some of the lines to an
+/// arbitrary writer.
+///
+/// Every line is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to`, `matchers` and `which` parameters work the same way as for
+/// `fizzbuzz_filtered()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_filtered_to, Filter, Matcher};
+///
+/// let mut out = Vec::new();
+/// fizzbuzz_filtered_to(&mut out, 1, 6, &Matcher::classic(), Filter::MatchedOnly).unwrap();
+///
+/// assert_eq!(out, b"Fizz\nBuzz\nFizz\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_filtered_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    which: Filter,
+) -> Result<(), FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    for i in from..=to {
+        let result = match classify(i, matchers) {
+            LineResult::Matched(words) if which != Filter::UnmatchedOnly => {
+                writeln!(writer, "{}", words.concat())
+            }
+            LineResult::Number(number) if which != Filter::MatchedOnly => {
+                writeln!(writer, "{}", number)
+            }
+            _ => continue,
+        };
+        result.map_err(FizzBuzzError::io_at(i))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matchers() -> Vec<Matcher> {
+        vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+            Matcher::new(7, "Bazz").unwrap(),
+        ]
+    }
+
+    #[test]
+    fn classify_triple() {
+        let matchers = matchers();
+
+        assert_eq!(
+            classify(105, &matchers),
+            LineResult::Matched(vec!["Fizz", "Buzz", "Bazz"])
+        );
+    }
+
+    #[test]
+    fn classify_single() {
+        let matchers = matchers();
+
+        assert_eq!(classify(3, &matchers), LineResult::Matched(vec!["Fizz"]));
+        assert_eq!(classify(10, &matchers), LineResult::Matched(vec!["Buzz"]));
+        assert_eq!(classify(14, &matchers), LineResult::Matched(vec!["Bazz"]));
+    }
+
+    #[test]
+    fn classify_none() {
+        let matchers = matchers();
+
+        assert_eq!(classify(1, &matchers), LineResult::Number(1));
+        assert_eq!(classify(11, &matchers), LineResult::Number(11));
+        assert_eq!(classify(11, &[]), LineResult::Number(11));
+    }
+
+    #[cfg(feature = "std")]
+    fn filtered(which: Filter) -> String {
+        let mut out = Vec::new();
+        fizzbuzz_filtered_to(&mut out, 1, 15, &Matcher::classic(), which).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn filtered_matched_only() {
+        assert_eq!(
+            filtered(Filter::MatchedOnly),
+            "Fizz\nBuzz\nFizz\nFizz\nBuzz\nFizz\nFizzBuzz\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn filtered_unmatched_only() {
+        assert_eq!(
+            filtered(Filter::UnmatchedOnly),
+            "1\n2\n4\n7\n8\n11\n13\n14\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn filtered_all() {
+        let mut expected = Vec::new();
+        crate::fizzbuzz_to(&mut expected, 1, 15, &Matcher::classic()).unwrap();
+
+        assert_eq!(Filter::default(), Filter::All);
+        assert_eq!(filtered(Filter::All).into_bytes(), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn filtered_from_bigger_than_to() {
+        let mut out = Vec::new();
+
+        assert!(matches!(
+            fizzbuzz_filtered_to(&mut out, 2, 1, &Matcher::classic(), Filter::All),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{line, Matcher};
+#[cfg(feature = "std")]
+use crate::{with_stdout, FizzBuzzError};
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// The colors words can be printed in by `line_colored()`.
+///
+/// These are the eight basic ANSI colors, which practically every terminal supports.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Get the ANSI escape sequence that switches the foreground to this color.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Color;
+    /// assert_eq!(Color::Green.escape(), "\x1b[32m");
+    /// ```
+    pub fn escape(self) -> &'static str {
+        match self {
+            Color::Black => "\x1b[30m",
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+            Color::White => "\x1b[37m",
+        }
+    }
+}
+
+/// The ANSI escape sequence that resets all colors.
+pub const RESET: &str = "\x1b[0m";
+
+/// Provides a configurable version of FizzBuzz for a single number, with colored words.
+///
+/// Works the same way as `line()`, but the word of every matching matcher with a color is wrapped
+/// in the ANSI escape sequences for that color. Every word is colored on its own, so that the
+/// words of different matchers can have different colors. Numbers are never colored.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_colored, Color, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap().with_color(Color::Green),
+///     Matcher::new(5, "Buzz").unwrap().with_color(Color::Blue),
+/// ];
+///
+/// assert_eq!(line_colored(3, &matchers), "\x1b[32mFizz\x1b[0m");
+/// assert_eq!(
+///     line_colored(15, &matchers),
+///     "\x1b[32mFizz\x1b[0m\x1b[34mBuzz\x1b[0m"
+/// );
+/// assert_eq!(line_colored(7, &matchers), "7");
+/// ```
+pub fn line_colored(number: usize, matchers: &[Matcher]) -> String {
+    let mut out = String::new();
+    let mut matched = false;
+
+    for m in matchers.iter().filter(|m| m.matches(number)) {
+        match m.color() {
+            Some(color) => {
+                out.push_str(color.escape());
+                out.push_str(m.word());
+                out.push_str(RESET);
+            }
+            None => out.push_str(m.word()),
+        }
+        matched = true;
+    }
+
+    if matched {
+        out
+    } else {
+        line(number, matchers)
+    }
+}
+
+/// Provides a configurable version of FizzBuzz, with colored words.
+///
+/// The output is written to stdout. Use `fizzbuzz_colored_to()` to write it somewhere else.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`. The words of
+/// the matchers are colored like `line_colored()` does.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+#[cfg(feature = "std")]
+pub fn fizzbuzz_colored(from: usize, to: usize, matchers: &[Matcher]) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_colored_to(out, from, to, matchers))
+}
+
+/// Provides a configurable version of FizzBuzz, with colored words and an arbitrary writer.
+///
+/// Every line is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz_colored()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_colored_to, Color, Matcher};
+///
+/// let matchers = vec![Matcher::new(3, "Fizz").unwrap().with_color(Color::Red)];
+///
+/// let mut out = Vec::new();
+/// fizzbuzz_colored_to(&mut out, 2, 3, &matchers).unwrap();
+///
+/// assert_eq!(out, b"2\n\x1b[31mFizz\x1b[0m\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_colored_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<(), FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    for i in from..=to {
+        writeln!(writer, "{}", line_colored(i, matchers)).map_err(FizzBuzzError::io_at(i))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matchers() -> Vec<Matcher> {
+        vec![
+            Matcher::new(3, "Fizz").unwrap().with_color(Color::Green),
+            Matcher::new(5, "Buzz").unwrap().with_color(Color::Blue),
+            Matcher::new(7, "Bazz").unwrap(),
+        ]
+    }
+
+    #[test]
+    fn line_colored_segments() {
+        let matchers = matchers();
+
+        assert_eq!(line_colored(3, &matchers), "\x1b[32mFizz\x1b[0m");
+        assert_eq!(line_colored(5, &matchers), "\x1b[34mBuzz\x1b[0m");
+        assert_eq!(
+            line_colored(15, &matchers),
+            "\x1b[32mFizz\x1b[0m\x1b[34mBuzz\x1b[0m"
+        );
+        assert_eq!(
+            line_colored(105, &matchers),
+            "\x1b[32mFizz\x1b[0m\x1b[34mBuzz\x1b[0mBazz"
+        );
+    }
+
+    #[test]
+    fn line_colored_uncolored() {
+        let matchers = matchers();
+
+        assert_eq!(line_colored(1, &matchers), "1");
+        assert_eq!(line_colored(7, &matchers), "Bazz");
+
+        for i in 0..200 {
+            assert_eq!(
+                line_colored(i, &Matcher::classic()),
+                line(i, &Matcher::classic())
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_colored_to_normal() {
+        let mut out = Vec::new();
+        fizzbuzz_colored_to(&mut out, 4, 6, &matchers()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "4\n\x1b[34mBuzz\x1b[0m\n\x1b[32mFizz\x1b[0m\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_colored_to_from_bigger_than_to() {
+        let mut out = Vec::new();
+
+        assert!(matches!(
+            fizzbuzz_colored_to(&mut out, 2, 1, &matchers()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+        assert!(out.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn color_serde() {
+        let matcher = Matcher::new(3, "Fizz").unwrap().with_color(Color::Green);
+
+        let json = serde_json::to_string(&matcher).unwrap();
+        assert_eq!(json, r#"{"number":3,"word":"Fizz","color":"green"}"#);
+
+        let parsed: Matcher = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, matcher);
+    }
+}
@@ -0,0 +1,392 @@
+use crate::{fizzbuzz_lines, FizzBuzzError, Matcher, MatcherError};
+#[cfg(feature = "std")]
+use crate::{fizzbuzz_to, with_stdout};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// A complete FizzBuzz setup: the range of numbers and the matchers to use.
+///
+/// Created with `FizzBuzzConfig::builder()`, or with `FizzBuzzConfig::default()` for the classic
+/// version of FizzBuzz. Since the configuration is validated when it is built, running it can
+/// only fail because of I/O errors.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::FizzBuzzConfig;
+///
+/// let config = FizzBuzzConfig::builder()
+///     .from(1)
+///     .to(15)
+///     .matcher(3, "Fizz")?
+///     .matcher(5, "Buzz")?
+///     .build()?;
+///
+/// assert_eq!(config.lines().len(), 15);
+/// assert_eq!(config.lines()[14], "FizzBuzz");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FizzBuzzConfig {
+    from: usize,
+    to: usize,
+    matchers: Vec<Matcher>,
+    skip_zero: bool,
+}
+
+impl FizzBuzzConfig {
+    /// Start building a new configuration.
+    ///
+    /// The range starts out as `1..=100`, without any matchers.
+    pub fn builder() -> FizzBuzzConfigBuilder {
+        FizzBuzzConfigBuilder {
+            from: 1,
+            to: 100,
+            matchers: Vec::new(),
+            skip_zero: false,
+        }
+    }
+
+    /// Get the first number of the range.
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// Get the last number of the range.
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    /// Get the matchers, in the order they are applied.
+    pub fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+
+    /// Get wether 0 is left out of the output.
+    pub fn skip_zero(&self) -> bool {
+        self.skip_zero
+    }
+
+    /// Get the first and last number output is produced for.
+    ///
+    /// Returns `None`, if no output is produced at all. This is only the case, if the range only
+    /// contains 0, and 0 is skipped.
+    fn range(&self) -> Option<(usize, usize)> {
+        if self.skip_zero && self.from == 0 {
+            if self.to == 0 {
+                return None;
+            }
+            return Some((1, self.to));
+        }
+
+        Some((self.from, self.to))
+    }
+
+    /// Print the output of FizzBuzz for the configuration to stdout.
+    ///
+    /// # Errors
+    /// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+    #[cfg(feature = "std")]
+    pub fn run(&self) -> Result<(), FizzBuzzError> {
+        with_stdout(|out| self.run_to(out))
+    }
+
+    /// Write the output of FizzBuzz for the configuration to `writer`.
+    ///
+    /// # Errors
+    /// - Returns `FizzBuzzError::Io`, if writing to `writer` fails.
+    ///
+    /// # Example
+    /// ```
+    /// use extended_fizzbuzz::FizzBuzzConfig;
+    ///
+    /// let config = FizzBuzzConfig::builder()
+    ///     .from(9)
+    ///     .to(11)
+    ///     .matcher(3, "Fizz")?
+    ///     .build()?;
+    ///
+    /// let mut out = Vec::new();
+    /// config.run_to(&mut out)?;
+    ///
+    /// assert_eq!(out, b"Fizz\n10\n11\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn run_to<W: Write>(&self, writer: &mut W) -> Result<(), FizzBuzzError> {
+        match self.range() {
+            Some((from, to)) => fizzbuzz_to(writer, from, to, &self.matchers),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the output of FizzBuzz for the configuration, one `String` per number.
+    pub fn lines(&self) -> Vec<String> {
+        match self.range() {
+            // The range was validated by `build()`.
+            Some((from, to)) => fizzbuzz_lines(from, to, &self.matchers).unwrap(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// The classic version of FizzBuzz: the numbers from 1 to 100, with `Matcher::classic()`.
+///
+/// This can't fail, since the range and the matchers are known to be valid.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::FizzBuzzConfig;
+///
+/// let lines = FizzBuzzConfig::default().lines();
+///
+/// assert_eq!(lines.len(), 100);
+/// assert_eq!(lines[..5], ["1", "2", "Fizz", "4", "Buzz"]);
+/// ```
+impl Default for FizzBuzzConfig {
+    fn default() -> Self {
+        FizzBuzzConfig {
+            from: 1,
+            to: 100,
+            matchers: Matcher::classic(),
+            skip_zero: false,
+        }
+    }
+}
+
+/// A builder for `FizzBuzzConfig`.
+///
+/// Created with `FizzBuzzConfig::builder()`.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct FizzBuzzConfigBuilder {
+    from: usize,
+    to: usize,
+    matchers: Vec<Matcher>,
+    skip_zero: bool,
+}
+
+impl FizzBuzzConfigBuilder {
+    /// Set the first number of the range.
+    pub fn from(mut self, from: usize) -> Self {
+        self.from = from;
+        self
+    }
+
+    /// Set the last number of the range.
+    pub fn to(mut self, to: usize) -> Self {
+        self.to = to;
+        self
+    }
+
+    /// Set wether 0 is left out of the output.
+    ///
+    /// 0 is divisible by every number, so every matcher created by `Matcher::new()` matches it.
+    /// If that isn't wanted, 0 can be skipped, which means no line is produced for it at all. By
+    /// default, 0 isn't skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use extended_fizzbuzz::FizzBuzzConfig;
+    ///
+    /// let builder = FizzBuzzConfig::builder().from(0).to(3).matcher(3, "Fizz")?;
+    ///
+    /// assert_eq!(builder.clone().build()?.lines(), vec!["Fizz", "1", "2", "Fizz"]);
+    /// assert_eq!(builder.skip_zero(true).build()?.lines(), vec!["1", "2", "Fizz"]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn skip_zero(mut self, skip_zero: bool) -> Self {
+        self.skip_zero = skip_zero;
+        self
+    }
+
+    /// Add a matcher, that substitutes all numbers divisible by `number` with `word`.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NumberIsZero`, if `number` is 0.
+    /// - Returns `MatcherError::EmptyWord`, if `word` is empty.
+    pub fn matcher(self, number: usize, word: &str) -> Result<Self, MatcherError> {
+        Ok(self.with_matcher(Matcher::new(number, word)?))
+    }
+
+    /// Add an already created matcher.
+    ///
+    /// This allows using other kinds of matchers than `Matcher::new()` creates.
+    pub fn with_matcher(mut self, matcher: Matcher) -> Self {
+        self.matchers.push(matcher);
+        self
+    }
+
+    /// Validate the configuration and create it.
+    ///
+    /// # Errors
+    /// - Returns `FizzBuzzError::FromBiggerThanTo`, if the first number of the range is bigger
+    ///   than the last one.
+    pub fn build(self) -> Result<FizzBuzzConfig, FizzBuzzError> {
+        if self.from > self.to {
+            return Err(FizzBuzzError::FromBiggerThanTo {
+                from: self.from,
+                to: self.to,
+            });
+        }
+
+        Ok(FizzBuzzConfig {
+            from: self.from,
+            to: self.to,
+            matchers: self.matchers,
+            skip_zero: self.skip_zero,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn config_run() {
+        let config = FizzBuzzConfig::builder()
+            .from(1)
+            .to(15)
+            .matcher(3, "Fizz")
+            .unwrap()
+            .matcher(5, "Buzz")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(config.from(), 1);
+        assert_eq!(config.to(), 15);
+        assert_eq!(config.matchers(), &Matcher::classic()[..]);
+
+        let mut out = Vec::new();
+        config.run_to(&mut out).unwrap();
+
+        let expected: String = config.lines().iter().map(|l| format!("{}\n", l)).collect();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+        assert_eq!(config.lines()[14], "FizzBuzz");
+    }
+
+    #[test]
+    fn config_builder_defaults() {
+        let config = FizzBuzzConfig::builder().build().unwrap();
+
+        assert_eq!(config.from(), 1);
+        assert_eq!(config.to(), 100);
+        assert!(config.matchers().is_empty());
+        assert_eq!(config.lines()[99], "100");
+    }
+
+    #[test]
+    fn config_with_matcher() {
+        let config = FizzBuzzConfig::builder()
+            .from(40)
+            .to(43)
+            .with_matcher(Matcher::exactly(42, "Answer"))
+            .build()
+            .unwrap();
+
+        assert_eq!(config.lines(), vec!["40", "41", "Answer", "43"]);
+    }
+
+    #[test]
+    fn config_default() {
+        let expected: Vec<_> = (1..=100)
+            .map(|i| match (i % 3, i % 5) {
+                (0, 0) => "FizzBuzz".to_string(),
+                (0, _) => "Fizz".to_string(),
+                (_, 0) => "Buzz".to_string(),
+                _ => i.to_string(),
+            })
+            .collect();
+
+        assert_eq!(FizzBuzzConfig::default().lines(), expected);
+    }
+
+    #[test]
+    fn config_from_bigger_than_to() {
+        assert!(matches!(
+            FizzBuzzConfig::builder().from(2).to(1).build(),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+
+    #[test]
+    fn config_number_is_zero() {
+        assert!(matches!(
+            FizzBuzzConfig::builder().matcher(0, "Zero"),
+            Err(MatcherError::NumberIsZero)
+        ));
+    }
+
+    #[test]
+    fn config_empty_word() {
+        assert!(matches!(
+            FizzBuzzConfig::builder().matcher(3, ""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    fn config_zero() {
+        let config = FizzBuzzConfig::builder()
+            .from(0)
+            .to(5)
+            .matcher(3, "Fizz")
+            .unwrap()
+            .matcher(5, "Buzz")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert!(!config.skip_zero());
+        assert_eq!(
+            config.lines(),
+            vec!["FizzBuzz", "1", "2", "Fizz", "4", "Buzz"]
+        );
+    }
+
+    #[test]
+    fn config_skip_zero() {
+        let builder = FizzBuzzConfig::builder()
+            .matcher(3, "Fizz")
+            .unwrap()
+            .skip_zero(true);
+
+        let config = builder.clone().from(0).to(3).build().unwrap();
+        assert!(config.skip_zero());
+        assert_eq!(config.lines(), vec!["1", "2", "Fizz"]);
+
+        let config = builder.clone().from(0).to(0).build().unwrap();
+        assert!(config.lines().is_empty());
+
+        // Ranges without 0 aren't affected.
+        let config = builder.from(2).to(3).build().unwrap();
+        assert_eq!(config.lines(), vec!["2", "Fizz"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn config_skip_zero_run() {
+        let config = FizzBuzzConfig::builder()
+            .from(0)
+            .to(0)
+            .matcher(3, "Fizz")
+            .unwrap();
+
+        let mut out = Vec::new();
+        config.clone().build().unwrap().run_to(&mut out).unwrap();
+        assert_eq!(out, b"Fizz\n");
+
+        let mut out = Vec::new();
+        config
+            .skip_zero(true)
+            .build()
+            .unwrap()
+            .run_to(&mut out)
+            .unwrap();
+        assert!(out.is_empty());
+    }
+}
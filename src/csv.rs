@@ -0,0 +1,101 @@
+use crate::{fizzbuzz_pairs, FizzBuzzError, Matcher};
+use alloc::string::{String, ToString};
+
+/// Provides a configurable version of FizzBuzz, that returns the output as CSV.
+///
+/// The output starts with the header row `number,value`, followed by a row for every number in
+/// the range. Each row contains the number, and the result of `line()` for it. Results containing
+/// commas, quotes or line breaks are quoted, with quotes inside of them doubled. Every row is
+/// terminated by a `\n`.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_csv, Matcher};
+///
+/// let csv = fizzbuzz_csv(2, 3, &Matcher::classic()).unwrap();
+///
+/// assert_eq!(csv, "number,value\n2,2\n3,Fizz\n");
+/// ```
+pub fn fizzbuzz_csv(from: usize, to: usize, matchers: &[Matcher]) -> Result<String, FizzBuzzError> {
+    let mut out = String::from("number,value\n");
+
+    for (n, value) in fizzbuzz_pairs(from, to, matchers)? {
+        out += &n.to_string();
+        out.push(',');
+        push_field(&value, &mut out);
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+/// Append `field` to `out`, quoting it if necessary.
+fn push_field(field: &str, out: &mut String) {
+    if !field.contains([',', '"', '\n', '\r']) {
+        out.push_str(field);
+        return;
+    }
+
+    out.push('"');
+    out.push_str(&field.replace('"', "\"\""));
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_normal() {
+        assert_eq!(
+            fizzbuzz_csv(1, 5, &Matcher::classic()).unwrap(),
+            "number,value\n1,1\n2,2\n3,Fizz\n4,4\n5,Buzz\n"
+        );
+    }
+
+    #[test]
+    fn csv_quoting() {
+        let matchers = vec![
+            Matcher::new(2, "Fizz, Buzz").unwrap(),
+            Matcher::new(3, "\"Bazz\"").unwrap(),
+        ];
+
+        assert_eq!(
+            fizzbuzz_csv(1, 6, &matchers).unwrap(),
+            concat!(
+                "number,value\n",
+                "1,1\n",
+                "2,\"Fizz, Buzz\"\n",
+                "3,\"\"\"Bazz\"\"\"\n",
+                "4,\"Fizz, Buzz\"\n",
+                "5,5\n",
+                "6,\"Fizz, Buzz\"\"Bazz\"\"\"\n",
+            )
+        );
+    }
+
+    #[test]
+    fn csv_line_break() {
+        let matchers = vec![Matcher::exactly(1, "Line\nBreak")];
+
+        assert_eq!(
+            fizzbuzz_csv(1, 1, &matchers).unwrap(),
+            "number,value\n1,\"Line\nBreak\"\n"
+        );
+    }
+
+    #[test]
+    fn csv_from_bigger_than_to() {
+        assert!(matches!(
+            fizzbuzz_csv(2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+}
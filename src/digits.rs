@@ -0,0 +1,132 @@
+use crate::{fallback_string, line_fmt, Matcher};
+use alloc::string::String;
+
+/// The digits `line_digits()` renders numbers with.
+///
+/// New scripts may be added in the future.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Digits {
+    /// The digits `0` to `9`, just like `line()`.
+    #[default]
+    Latin,
+    /// The Arabic-Indic digits `٠` to `٩`.
+    ArabicIndic,
+    /// The Extended Arabic-Indic digits `۰` to `۹`, as used for Persian and Urdu.
+    ExtendedArabicIndic,
+    /// The Devanagari digits `०` to `९`.
+    Devanagari,
+}
+
+impl Digits {
+    /// Get the digit representing 0.
+    ///
+    /// All scripts encode their digits in order, so the other digits directly follow it.
+    fn zero(self) -> char {
+        match self {
+            Digits::Latin => '0',
+            Digits::ArabicIndic => '\u{0660}',
+            Digits::ExtendedArabicIndic => '\u{06F0}',
+            Digits::Devanagari => '\u{0966}',
+        }
+    }
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that renders numbers in a
+/// different script.
+///
+/// This works the same way as `line()`, but if no matcher matches, the number is written using
+/// the chosen `digits`. The digits are still written from the most significant one to the least
+/// significant one, so right to left scripts display them correctly on their own. Words of
+/// matching matchers aren't changed.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// With `digits` you can choose the script numbers are rendered in.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_digits, Digits, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(line_digits(124, &matchers, Digits::Latin), "124");
+/// assert_eq!(line_digits(124, &matchers, Digits::ArabicIndic), "١٢٤");
+/// assert_eq!(line_digits(124, &matchers, Digits::Devanagari), "१२४");
+/// assert_eq!(line_digits(15, &matchers, Digits::Devanagari), "FizzBuzz");
+/// ```
+pub fn line_digits(number: usize, matchers: &[Matcher], digits: Digits) -> String {
+    line_fmt(number, matchers, |n| render(n, digits))
+}
+
+/// Write `number` using `digits`.
+fn render(number: usize, digits: Digits) -> String {
+    let zero = u32::from(digits.zero());
+
+    fallback_string(number)
+        .bytes()
+        .map(|b| {
+            // Both the offset and the digits of all scripts are valid code points.
+            core::char::from_u32(zero + u32::from(b - b'0')).unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line;
+
+    #[test]
+    fn digits_code_points() {
+        // 123 is divisible by 3, so Fizz is left out.
+        let matchers = vec![Matcher::buzz()];
+
+        assert_eq!(
+            line_digits(123, &matchers, Digits::ArabicIndic),
+            "\u{0661}\u{0662}\u{0663}"
+        );
+        assert_eq!(
+            line_digits(123, &matchers, Digits::ExtendedArabicIndic),
+            "\u{06F1}\u{06F2}\u{06F3}"
+        );
+        assert_eq!(
+            line_digits(123, &matchers, Digits::Devanagari),
+            "\u{0967}\u{0968}\u{0969}"
+        );
+        assert_eq!(line_digits(0, &[], Digits::Devanagari), "\u{0966}");
+        assert_eq!(
+            line_digits(7_890, &[], Digits::ArabicIndic),
+            "\u{0667}\u{0668}\u{0669}\u{0660}"
+        );
+    }
+
+    #[test]
+    fn digits_matched() {
+        let matchers = Matcher::classic();
+        let scripts = [
+            Digits::Latin,
+            Digits::ArabicIndic,
+            Digits::ExtendedArabicIndic,
+            Digits::Devanagari,
+        ];
+
+        for digits in scripts.iter() {
+            assert_eq!(line_digits(15, &matchers, *digits), "FizzBuzz");
+            assert_eq!(line_digits(9, &matchers, *digits), "Fizz");
+        }
+    }
+
+    #[test]
+    fn digits_latin_equals_line() {
+        let matchers = Matcher::classic();
+
+        for i in (0..1000).chain(usize::MAX - 10..=usize::MAX) {
+            assert_eq!(
+                line_digits(i, &matchers, Digits::default()),
+                line(i, &matchers)
+            );
+        }
+    }
+}
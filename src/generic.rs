@@ -0,0 +1,426 @@
+use crate::MatcherError;
+#[cfg(feature = "std")]
+use crate::{with_stdout, FizzBuzzError};
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An integer type FizzBuzz can be calculated for.
+///
+/// This trait is implemented for all primitive integer types. It can't be implemented outside of
+/// this crate.
+///
+/// For signed types, divisibility follows the usual sign rules, so `-15` is divisible by `3` and
+/// `-3`.
+pub trait Integer: Copy + Display + PartialEq + sealed::Sealed {
+    /// Check wether the value is 0.
+    fn is_zero(self) -> bool;
+
+    /// Check wether the value is divisible by `divisor`, which mustn't be 0.
+    fn is_divisible_by(self, divisor: Self) -> bool;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Integer for $t {
+                fn is_zero(self) -> bool {
+                    self == 0
+                }
+
+                fn is_divisible_by(self, divisor: Self) -> bool {
+                    self % divisor == 0
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_integer {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl Integer for $t {
+                fn is_zero(self) -> bool {
+                    self == 0
+                }
+
+                fn is_divisible_by(self, divisor: Self) -> bool {
+                    // `MIN % -1` overflows, but the remainder is 0 nonetheless.
+                    self.wrapping_rem(divisor) == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize);
+impl_signed_integer!(i8, i16, i32, i64, i128, isize);
+
+/// A matcher for signed numbers, as used by `iline()` and `ifizzbuzz()`.
+pub type IMatcher = MatcherFor<i64>;
+
+/// A container for configuration values, that works with any `Integer` type.
+///
+/// This is the generic counterpart of `Matcher`, which only works with `usize`.
+///
+/// # Example
+/// ```
+/// # use extended_fizzbuzz::MatcherFor;
+/// let fizz = MatcherFor::new(3u128, "Fizz").unwrap();
+///
+/// assert!(fizz.matches(u128::MAX));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MatcherFor<N> {
+    number: N,
+    word: String,
+}
+
+impl<N: Integer> MatcherFor<N> {
+    /// Create a new matcher.
+    ///
+    /// # Parameters
+    /// The `number` parameter works the same way as for `Matcher::new()`.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NumberIsZero` if the `number` parameter is 0.
+    pub fn new(number: N, word: &str) -> Result<Self, MatcherError> {
+        if number.is_zero() {
+            return Err(MatcherError::NumberIsZero);
+        }
+
+        Ok(MatcherFor {
+            number,
+            word: word.to_owned(),
+        })
+    }
+
+    /// Get the number the matcher checks against.
+    pub fn number(&self) -> N {
+        self.number
+    }
+
+    /// Get the word the matcher substitutes numbers with.
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// Check wether the `number` should be substituted.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::MatcherFor;
+    /// let matcher = MatcherFor::new(3u64, "Fizz").unwrap();
+    ///
+    /// assert_eq!(matcher.matches(2), false);
+    /// assert_eq!(matcher.matches(3), true);
+    /// assert_eq!(matcher.matches(4), false);
+    /// ```
+    pub fn matches(&self, number: N) -> bool {
+        number.is_divisible_by(self.number)
+    }
+
+    /// Get the text the `number` should be substituted by.
+    ///
+    /// If the `number` should be substituted, returns the appropriate text. Otherwise returns an
+    /// empty string.
+    pub fn text(&self, number: N) -> &str {
+        if self.matches(number) {
+            return &self.word;
+        }
+
+        ""
+    }
+}
+
+/// Provides a configurable version of FizzBuzz for a single number of any `Integer` type.
+///
+/// This is the generic counterpart of `line()`, which only works with `usize`.
+///
+/// # Parameters
+/// With `number`, you can provide the number to calculate the result for.
+///
+/// With `matchers` you can provide some `MatcherFor`s. These are used to configure how numbers
+/// are substituted with words. The matchers are tested in the order of the slice.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_for, MatcherFor};
+///
+/// let matchers = vec![
+///     MatcherFor::new(3u128, "Fizz").unwrap(),
+///     MatcherFor::new(5u128, "Buzz").unwrap(),
+/// ];
+///
+/// assert_eq!(line_for(1, &matchers), "1");
+/// assert_eq!(line_for(15, &matchers), "FizzBuzz");
+/// assert_eq!(line_for(u128::MAX, &matchers), "FizzBuzz");
+/// ```
+pub fn line_for<N: Integer>(number: N, matchers: &[MatcherFor<N>]) -> String {
+    let mut out = String::new();
+
+    for m in matchers.iter() {
+        out += m.text(number);
+    }
+
+    if out.is_empty() {
+        out += &number.to_string();
+    }
+
+    out
+}
+
+/// Provides a configurable version of FizzBuzz for a single signed number.
+///
+/// This works the same way as `line_for()`. Note that 0 is divisible by every number, so `iline(0,
+/// ..)` returns the words of all matchers concatenated.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{iline, IMatcher};
+///
+/// let matchers = vec![
+///     IMatcher::new(3, "Fizz").unwrap(),
+///     IMatcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// assert_eq!(iline(-15, &matchers), "FizzBuzz");
+/// assert_eq!(iline(-7, &matchers), "-7");
+/// assert_eq!(iline(0, &matchers), "FizzBuzz");
+/// ```
+pub fn iline(number: i64, matchers: &[IMatcher]) -> String {
+    line_for(number, matchers)
+}
+
+/// Provides a configurable version of FizzBuzz for signed numbers.
+///
+/// The output is written to stdout. Use `ifizzbuzz_to()` to write it somewhere else.
+///
+/// # Parameters
+/// The `from` and `to` parameters work the same way as for `fizzbuzz()`, but can be negative.
+///
+/// With `matchers` you can provide some `IMatcher`s. These are used to configure how numbers are
+/// substituted with words. The matchers are tested in the order of the slice.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::SignedFromBiggerThanTo`, if the `from` parameters value is bigger
+///   than the `to` parameters value.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{ifizzbuzz, IMatcher};
+///
+/// let matchers = vec![
+///     IMatcher::new(3, "Fizz").unwrap(),
+///     IMatcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// assert!(ifizzbuzz(-15, 15, &matchers).is_ok());
+/// assert!(ifizzbuzz(15, -15, &matchers).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn ifizzbuzz(from: i64, to: i64, matchers: &[IMatcher]) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| ifizzbuzz_to(out, from, to, matchers))
+}
+
+/// Provides a configurable version of FizzBuzz for signed numbers, that writes to an arbitrary
+/// writer.
+///
+/// Every line is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to` and `matchers` parameters work the same way as for `ifizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::SignedFromBiggerThanTo`, if the `from` parameters value is bigger
+///   than the `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails. Its `number` is always `None`,
+///   as it can't hold negative numbers.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{ifizzbuzz_to, IMatcher};
+///
+/// let matchers = vec![
+///     IMatcher::new(3, "Fizz").unwrap(),
+///     IMatcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// let mut out = Vec::new();
+/// ifizzbuzz_to(&mut out, -3, 1, &matchers).unwrap();
+///
+/// assert_eq!(out, b"Fizz\n-2\n-1\nFizzBuzz\n1\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn ifizzbuzz_to<W: Write>(
+    writer: &mut W,
+    from: i64,
+    to: i64,
+    matchers: &[IMatcher],
+) -> Result<(), FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::SignedFromBiggerThanTo { from, to });
+    }
+
+    for i in from..=to {
+        writeln!(writer, "{}", iline(i, matchers))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line, Matcher};
+    use rand::random;
+
+    #[test]
+    fn new_zero() {
+        assert!(matches!(
+            MatcherFor::new(0u128, "Test"),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            MatcherFor::new(0u8, "Test"),
+            Err(MatcherError::NumberIsZero)
+        ));
+    }
+
+    #[test]
+    fn line_for_u128_max() {
+        let number1 = u128::MAX / 3;
+        let number2 = u128::MAX;
+
+        let matchers = vec![
+            MatcherFor::new(number1, "Fizz").unwrap(),
+            MatcherFor::new(number2, "Buzz").unwrap(),
+        ];
+
+        assert_eq!(line_for(1, &matchers), "1");
+        assert_eq!(line_for(number1, &matchers), "Fizz");
+        assert_eq!(line_for(number1 * 2, &matchers), "Fizz");
+        assert_eq!(line_for(number2, &matchers), "FizzBuzz");
+        assert_eq!(line_for(number2 - 1, &matchers), (number2 - 1).to_string());
+    }
+
+    #[test]
+    fn line_for_equals_line() {
+        let matchers = Matcher::classic();
+        let matchers_for = vec![
+            MatcherFor::new(3usize, "Fizz").unwrap(),
+            MatcherFor::new(5usize, "Buzz").unwrap(),
+        ];
+
+        for _ in 0..100 {
+            let number: usize = random();
+            assert_eq!(line_for(number, &matchers_for), line(number, &matchers));
+        }
+    }
+
+    fn iclassic() -> Vec<IMatcher> {
+        vec![
+            IMatcher::new(3, "Fizz").unwrap(),
+            IMatcher::new(5, "Buzz").unwrap(),
+        ]
+    }
+
+    #[test]
+    fn iline_negative() {
+        let matchers = iclassic();
+
+        assert_eq!(iline(-1, &matchers), "-1");
+        assert_eq!(iline(-3, &matchers), "Fizz");
+        assert_eq!(iline(-5, &matchers), "Buzz");
+        assert_eq!(iline(-15, &matchers), "FizzBuzz");
+        assert_eq!(iline(-16, &matchers), "-16");
+    }
+
+    #[test]
+    fn iline_negative_divisor() {
+        let matchers = vec![IMatcher::new(-3, "Fizz").unwrap()];
+
+        assert_eq!(iline(3, &matchers), "Fizz");
+        assert_eq!(iline(-3, &matchers), "Fizz");
+        assert_eq!(iline(4, &matchers), "4");
+
+        let matchers = vec![IMatcher::new(-1, "One").unwrap()];
+        assert_eq!(iline(i64::MIN, &matchers), "One");
+    }
+
+    #[test]
+    fn iline_zero() {
+        let matchers = iclassic();
+        assert_eq!(iline(0, &matchers), "FizzBuzz");
+
+        let matchers = vec![
+            IMatcher::new(7, "Bazz").unwrap(),
+            IMatcher::new(-2, "Even").unwrap(),
+        ];
+        assert_eq!(iline(0, &matchers), "BazzEven");
+
+        assert!(matches!(
+            IMatcher::new(0, "Zero"),
+            Err(MatcherError::NumberIsZero)
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ifizzbuzz_to_sign_boundary() {
+        let matchers = iclassic();
+
+        let mut out = Vec::new();
+        ifizzbuzz_to(&mut out, -15, 15, &matchers).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+
+        assert_eq!(lines.len(), 31);
+        assert_eq!(lines[0], "FizzBuzz");
+        assert_eq!(lines[1], "-14");
+        assert_eq!(lines[14], "-1");
+        assert_eq!(lines[15], "FizzBuzz");
+        assert_eq!(lines[16], "1");
+        assert_eq!(lines[30], "FizzBuzz");
+
+        // The output is symmetric around 0, apart from the signs of unmatched numbers.
+        for i in 1..=15 {
+            let negative = lines[15 - i];
+            let positive = lines[15 + i];
+
+            if positive == i.to_string() {
+                assert_eq!(negative, format!("-{}", positive));
+            } else {
+                assert_eq!(negative, positive);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ifizzbuzz_to_from_bigger_than_to() {
+        let matchers = iclassic();
+
+        let mut out = Vec::new();
+        let res = ifizzbuzz_to(&mut out, 1, -1, &matchers);
+
+        assert!(matches!(
+            res,
+            Err(FizzBuzzError::SignedFromBiggerThanTo { from: 1, to: -1 })
+        ));
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{fizzbuzz_lines, with_stdout, FizzBuzzError, Matcher};
+use std::io::{self, Write};
+use unicode_width::UnicodeWidthStr;
+
+/// Provides a configurable version of FizzBuzz, that lays out the output in a grid.
+///
+/// The output is written to stdout. Use `fizzbuzz_grid_to()` to write it somewhere else.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// With `columns` you can set how many results are placed in each row. The results are filled in
+/// row by row, and the last row may contain fewer of them. All cells are as wide as the longest
+/// result, and separated by a single space. Results are aligned to the right, so that the digits
+/// of numbers line up.
+///
+/// Widths are measured in terminal columns, so words containing wide characters like emoji are
+/// aligned correctly.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+/// - Returns `FizzBuzzError::ColumnsIsZero`, if the `columns` parameters value is 0.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_grid, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert!(fizzbuzz_grid(1, 100, &matchers, 10).is_ok());
+/// assert!(fizzbuzz_grid(1, 100, &matchers, 0).is_err());
+/// ```
+pub fn fizzbuzz_grid(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    columns: usize,
+) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_grid_to(out, from, to, matchers, columns))
+}
+
+/// Provides a configurable version of FizzBuzz, that lays out the output in a grid and writes it
+/// to an arbitrary writer.
+///
+/// Every row is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to`, `matchers` and `columns` parameters work the same way as for
+/// `fizzbuzz_grid()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::ColumnsIsZero`, if the `columns` parameters value is 0. Nothing is
+///   written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails. A failed separator belongs to
+///   the result after it, a failed line break to the last result of its row.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_grid_to, Matcher};
+///
+/// let mut out = Vec::new();
+/// fizzbuzz_grid_to(&mut out, 1, 5, &Matcher::classic(), 3).unwrap();
+///
+/// assert_eq!(String::from_utf8(out).unwrap(), "   1    2 Fizz\n   4 Buzz\n");
+/// ```
+pub fn fizzbuzz_grid_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    columns: usize,
+) -> Result<(), FizzBuzzError> {
+    if columns == 0 {
+        return Err(FizzBuzzError::ColumnsIsZero);
+    }
+
+    // The width of every cell depends on all results, so they have to be computed in advance.
+    let lines = fizzbuzz_lines(from, to, matchers)?;
+    let width = lines.iter().map(|l| l.width()).max().unwrap_or(0);
+
+    for (row_index, row) in lines.chunks(columns).enumerate() {
+        let first_number = from + row_index * columns;
+
+        for (col, cell) in row.iter().enumerate() {
+            let number = first_number + col;
+
+            if col > 0 {
+                write!(writer, " ").map_err(FizzBuzzError::io_at(number))?;
+            }
+            write_cell(writer, cell, width).map_err(FizzBuzzError::io_at(number))?;
+        }
+
+        writeln!(writer).map_err(FizzBuzzError::io_at(first_number + row.len() - 1))?;
+    }
+
+    Ok(())
+}
+
+/// Write `cell` to `writer`, aligned to the right of `width` terminal columns.
+fn write_cell<W: Write>(writer: &mut W, cell: &str, width: usize) -> io::Result<()> {
+    let padding = width.saturating_sub(cell.width());
+    write!(writer, "{:padding$}{}", "", cell, padding = padding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::FailingWriter;
+
+    fn grid(from: usize, to: usize, matchers: &[Matcher], columns: usize) -> String {
+        let mut out = Vec::new();
+        fizzbuzz_grid_to(&mut out, from, to, matchers, columns).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn grid_partial_row() {
+        assert_eq!(
+            grid(1, 10, &Matcher::classic(), 3),
+            "   1    2 Fizz\n   4 Buzz Fizz\n   7    8 Fizz\nBuzz\n"
+        );
+    }
+
+    #[test]
+    fn grid_full_rows() {
+        assert_eq!(
+            grid(13, 18, &Matcher::classic(), 3),
+            "      13       14 FizzBuzz\n      16       17     Fizz\n"
+        );
+    }
+
+    #[test]
+    fn grid_single_column() {
+        let expected: String = fizzbuzz_lines(1, 15, &Matcher::classic())
+            .unwrap()
+            .iter()
+            .map(|l| format!("{:>8}\n", l))
+            .collect();
+
+        assert_eq!(grid(1, 15, &Matcher::classic(), 1), expected);
+    }
+
+    #[test]
+    fn grid_more_columns_than_numbers() {
+        assert_eq!(grid(8, 10, &Matcher::classic(), 5), "   8 Fizz Buzz\n");
+    }
+
+    #[test]
+    fn grid_columns_is_zero() {
+        let mut out = Vec::new();
+
+        assert!(matches!(
+            fizzbuzz_grid_to(&mut out, 1, 10, &Matcher::classic(), 0),
+            Err(FizzBuzzError::ColumnsIsZero)
+        ));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn grid_from_bigger_than_to() {
+        let mut out = Vec::new();
+
+        assert!(matches!(
+            fizzbuzz_grid_to(&mut out, 2, 1, &Matcher::classic(), 3),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+        assert!(out.is_empty());
+    }
+
+    fn failing_number(fail_on: u8, matchers: &[Matcher]) -> Option<usize> {
+        let mut writer = FailingWriter::on_byte(fail_on);
+
+        match fizzbuzz_grid_to(&mut writer, 1, 6, matchers, 3) {
+            Err(FizzBuzzError::Io { number, .. }) => number,
+            res => panic!("expected an io error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn grid_io_error_number() {
+        assert_eq!(failing_number(b'4', &Matcher::classic()), Some(4));
+        assert_eq!(failing_number(b' ', &[]), Some(2));
+        assert_eq!(failing_number(b'\n', &Matcher::classic()), Some(3));
+    }
+
+    #[test]
+    fn grid_wide_characters() {
+        let matchers = vec![Matcher::new(3, "🍕").unwrap()];
+
+        let out = grid(1, 6, &matchers, 3);
+        assert_eq!(out, " 1  2 🍕\n 4  5 🍕\n");
+
+        let mut matchers = Matcher::classic();
+        matchers.push(Matcher::new(7, "🍕").unwrap());
+
+        let out = grid(1, 21, &matchers, 4);
+        let widths: Vec<_> = out.lines().map(|l| l.width()).collect();
+        assert!(widths[..widths.len() - 1].iter().all(|w| *w == widths[0]));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{fallback_string, line_fmt, Matcher};
+use alloc::string::String;
+
+/// The threshold `line_grouped()` uses, above which the digits of numbers are grouped.
+pub const DEFAULT_GROUPING_THRESHOLD: usize = 1_000_000;
+
+/// Provides a configurable version of FizzBuzz for a single number, that groups the digits of big
+/// numbers.
+///
+/// This works the same way as `line_grouped_above()`, using `DEFAULT_GROUPING_THRESHOLD` as the
+/// threshold.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_grouped, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(line_grouped(1_000_001, &matchers), "1_000_001");
+/// assert_eq!(line_grouped(1_000_010, &matchers), "Buzz");
+/// assert_eq!(line_grouped(999_998, &matchers), "999998");
+/// ```
+pub fn line_grouped(number: usize, matchers: &[Matcher]) -> String {
+    line_grouped_above(number, matchers, DEFAULT_GROUPING_THRESHOLD)
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that groups the digits of
+/// numbers above a threshold.
+///
+/// This works the same way as `line()`, but if no matcher matches a number bigger than
+/// `threshold`, a `_` is put between every group of three digits, counted from the right. Smaller
+/// numbers aren't changed. Words of matching matchers aren't changed either.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// With `threshold` you can set the biggest number that is still printed without grouping. A
+/// `threshold` of 999 groups every number that has more than three digits.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_grouped_above, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(line_grouped_above(1001, &matchers, 1000), "1_001");
+/// assert_eq!(line_grouped_above(1000, &matchers, 1000), "Buzz");
+/// assert_eq!(line_grouped_above(998, &matchers, 0), "998");
+/// assert_eq!(line_grouped_above(1001, &matchers, 10_000), "1001");
+/// ```
+pub fn line_grouped_above(number: usize, matchers: &[Matcher], threshold: usize) -> String {
+    line_fmt(number, matchers, |n| {
+        if n > threshold {
+            group(n)
+        } else {
+            fallback_string(n)
+        }
+    })
+}
+
+/// Format `number` with a `_` between every group of three digits.
+fn group(number: usize) -> String {
+    let digits = fallback_string(number);
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push('_');
+        }
+        out.push(digit);
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line;
+
+    #[test]
+    fn grouped_unmatched() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_grouped(1_234_567, &matchers), "1_234_567");
+        assert_eq!(line_grouped(1_000_001, &matchers), "1_000_001");
+        assert_eq!(line_grouped(1, &matchers), "1");
+    }
+
+    #[test]
+    fn grouped_threshold() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_grouped(999_998, &matchers), "999998");
+        assert_eq!(line_grouped(1_000_001, &matchers), "1_000_001");
+        assert_eq!(line_grouped(12_344, &matchers), "12344");
+
+        assert_eq!(line_grouped_above(12_344, &matchers, 12_343), "12_344");
+        assert_eq!(line_grouped_above(12_344, &matchers, 12_344), "12344");
+        assert_eq!(
+            line_grouped_above(1_000_001, &matchers, usize::MAX),
+            "1000001"
+        );
+    }
+
+    #[test]
+    fn grouped_matched() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_grouped(15, &matchers), "FizzBuzz");
+        assert_eq!(line_grouped(1_234_560, &matchers), "FizzBuzz");
+        for i in 0..1000 {
+            assert_eq!(line_grouped(i, &matchers), line(i, &matchers));
+        }
+    }
+
+    #[test]
+    fn group_boundaries() {
+        assert_eq!(group(0), "0");
+        assert_eq!(group(999), "999");
+        assert_eq!(group(1000), "1_000");
+        assert_eq!(group(100_000), "100_000");
+        assert_eq!(group(1_000_000), "1_000_000");
+        assert_eq!(group(usize::MAX).replace('_', ""), usize::MAX.to_string());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{FizzBuzzError, Matcher};
+use alloc::string::String;
+
+/// The characters cells are shaded with, indexed by the number of matching matchers.
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// Render a heatmap of how many matchers match the numbers in a range.
+///
+/// Every number is rendered as a single block character, whose shading depends on the number of
+/// matching matchers: ` ` for none, `░` for one, `▒` for two, `▓` for three and `█` for four or
+/// more. The cells are filled in row by row, and every row is terminated by a `\n`. The last row
+/// may contain fewer cells.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// With `width` you can set how many cells are placed in each row.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+/// - Returns `FizzBuzzError::ColumnsIsZero`, if the `width` parameters value is 0.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{render_heatmap, Matcher};
+///
+/// let heatmap = render_heatmap(1, 15, &Matcher::classic(), 5).unwrap();
+///
+/// assert_eq!(heatmap, "  ░ ░\n░  ░░\n ░  ▒\n");
+/// ```
+pub fn render_heatmap(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    width: usize,
+) -> Result<String, FizzBuzzError> {
+    if width == 0 {
+        return Err(FizzBuzzError::ColumnsIsZero);
+    }
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let mut out = String::new();
+    let mut until_break = width;
+
+    for number in from..=to {
+        let matched = matchers.iter().filter(|m| m.matches(number)).count();
+        out.push(SHADES[matched.min(SHADES.len() - 1)]);
+
+        until_break -= 1;
+        if until_break == 0 {
+            out.push('\n');
+            until_break = width;
+        }
+    }
+    if until_break != width {
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heatmap_small() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(
+            render_heatmap(1, 15, &matchers, 5).unwrap(),
+            "  ░ ░\n░  ░░\n ░  ▒\n"
+        );
+        assert_eq!(
+            render_heatmap(1, 15, &matchers, 4).unwrap(),
+            "  ░ \n░░  \n░░ ░\n  ▒\n"
+        );
+        assert_eq!(render_heatmap(15, 15, &matchers, 10).unwrap(), "▒\n");
+    }
+
+    #[test]
+    fn heatmap_shades() {
+        let matchers = vec![
+            Matcher::new(2, "Two").unwrap(),
+            Matcher::new(3, "Three").unwrap(),
+            Matcher::new(5, "Five").unwrap(),
+            Matcher::new(7, "Seven").unwrap(),
+            Matcher::new(11, "Eleven").unwrap(),
+        ];
+
+        // 0 matches everything, so the shading stays at the darkest character.
+        assert_eq!(render_heatmap(0, 0, &matchers, 1).unwrap(), "█\n");
+        assert_eq!(render_heatmap(1, 6, &matchers, 6).unwrap(), " ░░░░▒\n");
+        assert_eq!(render_heatmap(1, 3, &[], 2).unwrap(), "  \n \n");
+    }
+
+    #[test]
+    fn heatmap_invalid() {
+        assert!(matches!(
+            render_heatmap(1, 15, &Matcher::classic(), 0),
+            Err(FizzBuzzError::ColumnsIsZero)
+        ));
+        assert!(matches!(
+            render_heatmap(15, 1, &Matcher::classic(), 5),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 15, to: 1 })
+        ));
+    }
+}
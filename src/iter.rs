@@ -0,0 +1,573 @@
+use crate::{line, FizzBuzzError, Matcher};
+use alloc::string::String;
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+/// A lazy version of FizzBuzz.
+///
+/// Yields the output of `line()` for every number in the range, without computing the lines in
+/// advance. Can be created with the `fizzbuzz_iter()` function.
+///
+/// # Length
+/// The length of a range containing every possible `usize` value can't be represented as a
+/// `usize`. Calling `len()` on such an iterator will panic.
+///
+/// # Example
+/// ```
+/// # use extended_fizzbuzz::{fizzbuzz_iter, Matcher};
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// let mut iter = fizzbuzz_iter(1, 15, &matchers).unwrap();
+///
+/// assert_eq!(iter.len(), 15);
+/// assert_eq!(iter.next(), Some("1".to_string()));
+/// assert_eq!(iter.next_back(), Some("FizzBuzz".to_string()));
+/// assert_eq!(iter.len(), 13);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FizzBuzzIter<'a> {
+    /// The indices of the remaining numbers, counted from `first`.
+    range: RangeInclusive<usize>,
+    first: usize,
+    step: usize,
+    direction: Direction,
+    matchers: &'a [Matcher],
+}
+
+impl<'a> FizzBuzzIter<'a> {
+    /// Get the number at `index`.
+    ///
+    /// The `index` is never bigger than the distance between the first and last number divided by
+    /// the step, so this can't overflow.
+    fn number(&self, index: usize) -> usize {
+        match self.direction {
+            Direction::Ascending => self.first + index * self.step,
+            Direction::Descending => self.first - index * self.step,
+        }
+    }
+}
+
+impl<'a> Iterator for FizzBuzzIter<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range
+            .next()
+            .map(|i| line(self.number(i), self.matchers))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for FizzBuzzIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range
+            .next_back()
+            .map(|i| line(self.number(i), self.matchers))
+    }
+}
+
+impl<'a> ExactSizeIterator for FizzBuzzIter<'a> {}
+
+impl<'a> FusedIterator for FizzBuzzIter<'a> {}
+
+/// Provides a lazy version of FizzBuzz.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`. The
+/// `matchers` are borrowed for as long as the iterator lives.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_iter, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// let lines: Vec<_> = fizzbuzz_iter(1, 100, &matchers).unwrap().take(5).collect();
+/// assert_eq!(lines, vec!["1", "2", "Fizz", "4", "Buzz"]);
+///
+/// assert!(fizzbuzz_iter(100, 1, &matchers).is_err());
+/// ```
+pub fn fizzbuzz_iter(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<FizzBuzzIter<'_>, FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    Ok(fizzbuzz_range(
+        RangeSpec::new(from, to, Direction::Ascending, 1)?,
+        matchers,
+    ))
+}
+
+/// The directions a `RangeSpec` can count in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    /// Count up, from smaller to bigger numbers.
+    Ascending,
+    /// Count down, from bigger to smaller numbers.
+    Descending,
+}
+
+/// The numbers FizzBuzz is run for by `fizzbuzz_range()`.
+///
+/// A range starts at `start` and counts in its direction in steps of `step`. The `end` is
+/// inclusive, but only reached if it is a whole number of steps away from `start`. The range
+/// never steps past `end`, so it can't overflow.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{Direction, RangeSpec};
+///
+/// assert!(RangeSpec::new(10, 1, Direction::Descending, 2).is_ok());
+/// assert!(RangeSpec::new(10, 1, Direction::Ascending, 2).is_err());
+/// assert!(RangeSpec::new(1, 10, Direction::Ascending, 0).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RangeSpec {
+    start: usize,
+    end: usize,
+    direction: Direction,
+    step: usize,
+}
+
+impl RangeSpec {
+    /// Create a new range.
+    ///
+    /// # Parameters
+    /// The `start` parameter is the first number of the range.
+    ///
+    /// The `end` parameter is the number the range ends at. It is included, if it can be reached
+    /// from `start` in steps of `step`.
+    ///
+    /// The `direction` parameter sets wether the range counts up or down.
+    ///
+    /// The `step` parameter is the distance between two numbers of the range.
+    ///
+    /// # Errors
+    /// - Returns `FizzBuzzError::FromBiggerThanTo`, if the range is ascending and `start` is
+    ///   bigger than `end`.
+    /// - Returns `FizzBuzzError::FromSmallerThanTo`, if the range is descending and `start` is
+    ///   smaller than `end`.
+    /// - Returns `FizzBuzzError::StepIsZero`, if the `step` parameter is 0.
+    pub fn new(
+        start: usize,
+        end: usize,
+        direction: Direction,
+        step: usize,
+    ) -> Result<Self, FizzBuzzError> {
+        match direction {
+            Direction::Ascending if start > end => {
+                return Err(FizzBuzzError::FromBiggerThanTo {
+                    from: start,
+                    to: end,
+                })
+            }
+            Direction::Descending if start < end => {
+                return Err(FizzBuzzError::FromSmallerThanTo {
+                    from: start,
+                    to: end,
+                })
+            }
+            _ => {}
+        }
+        if step == 0 {
+            return Err(FizzBuzzError::StepIsZero);
+        }
+
+        Ok(RangeSpec {
+            start,
+            end,
+            direction,
+            step,
+        })
+    }
+
+    /// Get the first number of the range.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Get the number the range ends at.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Get the direction the range counts in.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Get the distance between two numbers of the range.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+}
+
+/// Provides a lazy version of FizzBuzz, that runs over an arbitrary range.
+///
+/// # Parameters
+/// The `spec` parameter defines the numbers to run FizzBuzz for. As it is checked when it is
+/// created, this function can't fail.
+///
+/// The `matchers` parameter works the same way as for `fizzbuzz()`. The `matchers` are borrowed
+/// for as long as the iterator lives.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_range, Direction, Matcher, RangeSpec};
+///
+/// let matchers = Matcher::classic();
+/// let spec = RangeSpec::new(10, 1, Direction::Descending, 2).unwrap();
+///
+/// let lines: Vec<_> = fizzbuzz_range(spec, &matchers).collect();
+/// assert_eq!(lines, vec!["Buzz", "8", "Fizz", "4", "2"]);
+/// ```
+pub fn fizzbuzz_range(spec: RangeSpec, matchers: &[Matcher]) -> FizzBuzzIter<'_> {
+    let distance = match spec.direction {
+        Direction::Ascending => spec.end - spec.start,
+        Direction::Descending => spec.start - spec.end,
+    };
+
+    FizzBuzzIter {
+        range: 0..=distance / spec.step,
+        first: spec.start,
+        step: spec.step,
+        direction: spec.direction,
+        matchers,
+    }
+}
+
+/// Provides a lazy version of FizzBuzz, that yields the numbers along with their results.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`. The
+/// `matchers` are borrowed for as long as the iterator lives.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_pairs, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// let pairs: Vec<_> = fizzbuzz_pairs(4, 6, &matchers).unwrap().collect();
+/// assert_eq!(
+///     pairs,
+///     vec![(4, "4".to_string()), (5, "Buzz".to_string()), (6, "Fizz".to_string())]
+/// );
+/// ```
+pub fn fizzbuzz_pairs(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<impl DoubleEndedIterator<Item = (usize, String)> + FusedIterator + '_, FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    Ok((from..=to).map(move |n| (n, line(n, matchers))))
+}
+
+/// A lazy version of FizzBuzz over the numbers of another iterator.
+///
+/// Yields the output of `line()` for every number the inner iterator yields. Can be created with
+/// `FizzBuzzExt::fizzbuzz()`.
+#[derive(Clone, Debug)]
+pub struct FizzBuzzMap<'a, I> {
+    iter: I,
+    matchers: &'a [Matcher],
+}
+
+impl<'a, I: Iterator<Item = usize>> Iterator for FizzBuzzMap<'a, I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|i| line(i, self.matchers))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I: DoubleEndedIterator<Item = usize>> DoubleEndedIterator for FizzBuzzMap<'a, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|i| line(i, self.matchers))
+    }
+}
+
+impl<'a, I: ExactSizeIterator<Item = usize>> ExactSizeIterator for FizzBuzzMap<'a, I> {}
+
+impl<'a, I: FusedIterator<Item = usize>> FusedIterator for FizzBuzzMap<'a, I> {}
+
+/// Adds FizzBuzz to every iterator over numbers.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{FizzBuzzExt, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// let lines: Vec<_> = (1..=100).filter(|i| i % 2 == 0).fizzbuzz(&matchers).take(3).collect();
+/// assert_eq!(lines, vec!["2", "4", "Fizz"]);
+/// ```
+pub trait FizzBuzzExt: Iterator<Item = usize> + Sized {
+    /// Run FizzBuzz for every number of the iterator.
+    ///
+    /// The `matchers` parameter works the same way as for `fizzbuzz()`. The `matchers` are
+    /// borrowed for as long as the returned iterator lives.
+    fn fizzbuzz(self, matchers: &[Matcher]) -> FizzBuzzMap<'_, Self> {
+        FizzBuzzMap {
+            iter: self,
+            matchers,
+        }
+    }
+}
+
+impl<I: Iterator<Item = usize>> FizzBuzzExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iter_normal() {
+        let matchers = Matcher::classic();
+
+        let lines: Vec<_> = fizzbuzz_iter(1, 15, &matchers).unwrap().collect();
+        let expected: Vec<_> = (1..=15).map(|i| line(i, &matchers)).collect();
+
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let matchers = Matcher::classic();
+
+        let lines: Vec<_> = fizzbuzz_iter(1, 15, &matchers).unwrap().rev().collect();
+
+        assert_eq!(lines.len(), 15);
+        assert_eq!(lines[0], "FizzBuzz");
+        assert_eq!(lines[14], "1");
+    }
+
+    #[test]
+    fn iter_len() {
+        let matchers = Matcher::classic();
+
+        let mut iter = fizzbuzz_iter(1, 10, &matchers).unwrap();
+        assert_eq!(iter.len(), 10);
+
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 8);
+
+        let iter = fizzbuzz_iter(usize::MAX - 1, usize::MAX, &matchers).unwrap();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.count(), 2);
+    }
+
+    #[test]
+    fn iter_fused() {
+        let matchers = Matcher::classic();
+
+        let mut iter = fizzbuzz_iter(3, 3, &matchers).unwrap();
+
+        assert_eq!(iter.next(), Some("Fizz".to_string()));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn iter_from_bigger_than_to() {
+        let matchers = Matcher::classic();
+
+        assert!(matches!(
+            fizzbuzz_iter(2, 1, &matchers),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+
+    #[test]
+    fn range_descending() {
+        let matchers = Matcher::classic();
+        let spec = RangeSpec::new(10, 2, Direction::Descending, 2).unwrap();
+
+        let lines: Vec<_> = fizzbuzz_range(spec, &matchers).collect();
+        let expected: Vec<_> = [10, 8, 6, 4, 2]
+            .iter()
+            .map(|i| line(*i, &matchers))
+            .collect();
+
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn range_ascending() {
+        let matchers = Matcher::classic();
+        let spec = RangeSpec::new(1, 15, Direction::Ascending, 3).unwrap();
+
+        let lines: Vec<_> = fizzbuzz_range(spec, &matchers).collect();
+        let expected: Vec<_> = [1, 4, 7, 10, 13]
+            .iter()
+            .map(|i| line(*i, &matchers))
+            .collect();
+
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn range_rev_and_len() {
+        let matchers = Matcher::classic();
+        let spec = RangeSpec::new(20, 1, Direction::Descending, 7).unwrap();
+
+        let mut iter = fizzbuzz_range(spec, &matchers);
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(line(6, &matchers)));
+        assert_eq!(iter.next(), Some(line(20, &matchers)));
+        assert_eq!(iter.next(), Some(line(13, &matchers)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn range_no_overflow() {
+        let matchers = Matcher::classic();
+
+        let spec = RangeSpec::new(usize::MAX - 4, usize::MAX, Direction::Ascending, 3).unwrap();
+        let lines: Vec<_> = fizzbuzz_range(spec, &matchers).collect();
+        assert_eq!(
+            lines,
+            vec![
+                line(usize::MAX - 4, &matchers),
+                line(usize::MAX - 1, &matchers)
+            ]
+        );
+
+        let spec = RangeSpec::new(4, 0, Direction::Descending, 3).unwrap();
+        let lines: Vec<_> = fizzbuzz_range(spec, &matchers).collect();
+        assert_eq!(lines, vec!["4", "1"]);
+
+        let spec = RangeSpec::new(usize::MAX - 1, usize::MAX, Direction::Ascending, 5).unwrap();
+        let mut iter = fizzbuzz_range(spec, &matchers);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(line(usize::MAX - 1, &matchers)));
+        assert_eq!(iter.next(), None);
+
+        let spec = RangeSpec::new(1, 0, Direction::Descending, 5).unwrap();
+        let lines: Vec<_> = fizzbuzz_range(spec, &matchers).collect();
+        assert_eq!(lines, vec!["1"]);
+    }
+
+    #[test]
+    fn range_invalid() {
+        assert!(matches!(
+            RangeSpec::new(2, 1, Direction::Ascending, 1),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+        assert!(matches!(
+            RangeSpec::new(1, 2, Direction::Descending, 1),
+            Err(FizzBuzzError::FromSmallerThanTo { from: 1, to: 2 })
+        ));
+        assert!(matches!(
+            RangeSpec::new(1, 2, Direction::Ascending, 0),
+            Err(FizzBuzzError::StepIsZero)
+        ));
+        assert!(RangeSpec::new(3, 3, Direction::Descending, 1).is_ok());
+    }
+
+    #[test]
+    fn pairs_normal() {
+        let matchers = Matcher::classic();
+
+        let pairs: Vec<_> = fizzbuzz_pairs(1, 5, &matchers).unwrap().collect();
+
+        assert_eq!(
+            pairs,
+            vec![
+                (1, "1".to_string()),
+                (2, "2".to_string()),
+                (3, "Fizz".to_string()),
+                (4, "4".to_string()),
+                (5, "Buzz".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn pairs_rev() {
+        let matchers = Matcher::classic();
+
+        let mut pairs = fizzbuzz_pairs(usize::MAX - 1, usize::MAX, &matchers).unwrap();
+
+        assert_eq!(
+            pairs.next_back(),
+            Some((usize::MAX, line(usize::MAX, &matchers)))
+        );
+        assert_eq!(
+            pairs.next(),
+            Some((usize::MAX - 1, line(usize::MAX - 1, &matchers)))
+        );
+        assert_eq!(pairs.next(), None);
+    }
+
+    #[test]
+    fn pairs_from_bigger_than_to() {
+        assert!(matches!(
+            fizzbuzz_pairs(2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+
+    #[test]
+    fn ext_normal() {
+        let classic = Matcher::classic();
+
+        assert_eq!(
+            (1..=5).fizzbuzz(&classic).collect::<Vec<_>>(),
+            vec!["1", "2", "Fizz", "4", "Buzz"]
+        );
+        assert_eq!(
+            vec![15, 7, 9]
+                .into_iter()
+                .fizzbuzz(&classic)
+                .collect::<Vec<_>>(),
+            vec!["FizzBuzz", "7", "Fizz"]
+        );
+    }
+
+    #[test]
+    fn ext_rev_and_len() {
+        let classic = Matcher::classic();
+
+        let mut iter = (1..11).fizzbuzz(&classic);
+
+        assert_eq!(iter.len(), 10);
+        assert_eq!(iter.next_back(), Some("Buzz".to_string()));
+        assert_eq!(iter.len(), 9);
+        assert_eq!(iter.next(), Some("1".to_string()));
+
+        let rev: Vec<_> = iter.rev().take(2).collect();
+        assert_eq!(rev, vec!["Fizz", "8"]);
+    }
+}
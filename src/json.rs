@@ -0,0 +1,78 @@
+use crate::{fizzbuzz_pairs, FizzBuzzError, Matcher};
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// The serialized form of the result for a single number.
+#[derive(Serialize)]
+struct Entry {
+    n: usize,
+    value: String,
+}
+
+/// Provides a configurable version of FizzBuzz, that returns the output as JSON.
+///
+/// The output is an array, that contains an object for every number in the range. Each object
+/// contains the number as `n`, and the result of `line()` for it as `value`.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_json, Matcher};
+///
+/// let json = fizzbuzz_json(2, 3, &Matcher::classic()).unwrap();
+///
+/// assert_eq!(json, r#"[{"n":2,"value":"2"},{"n":3,"value":"Fizz"}]"#);
+/// ```
+pub fn fizzbuzz_json(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<String, FizzBuzzError> {
+    let entries: Vec<_> = fizzbuzz_pairs(from, to, matchers)?
+        .map(|(n, value)| Entry { n, value })
+        .collect();
+
+    // Numbers and strings can always be represented in JSON.
+    Ok(serde_json::to_string(&entries).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_normal() {
+        assert_eq!(
+            fizzbuzz_json(1, 5, &Matcher::classic()).unwrap(),
+            concat!(
+                r#"[{"n":1,"value":"1"},{"n":2,"value":"2"},{"n":3,"value":"Fizz"},"#,
+                r#"{"n":4,"value":"4"},{"n":5,"value":"Buzz"}]"#
+            )
+        );
+    }
+
+    #[test]
+    fn json_escaping() {
+        let matchers = vec![Matcher::new(2, "\"Even\"").unwrap()];
+
+        assert_eq!(
+            fizzbuzz_json(2, 2, &matchers).unwrap(),
+            r#"[{"n":2,"value":"\"Even\""}]"#
+        );
+    }
+
+    #[test]
+    fn json_from_bigger_than_to() {
+        assert!(matches!(
+            fizzbuzz_json(2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+}
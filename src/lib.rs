@@ -1,5 +1,8 @@
 //! This library provides a configurable FizzBuzz implementation.
 //!
+//! Without the default `std` feature, the library is `no_std` and only needs `alloc`. Everything
+//! except the functions printing to stdout or writing to an `io::Write` is available then.
+//!
 //! # Examples
 //! ## FizzBuzz from 1 to 15
 //! ```
@@ -47,24 +50,113 @@
 //! assert_eq!(line(15, &matchers), "FizzBuzz".to_string());
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+// Used by the `matchers!` macro, which also has to work in crates without `std`.
+#[doc(hidden)]
+pub extern crate alloc as __alloc;
+
+#[cfg(feature = "tokio")]
+mod async_io;
+mod cache;
+mod classify;
+#[cfg(feature = "color")]
+mod color;
+mod config;
+mod csv;
+mod digits;
+mod generic;
+#[cfg(feature = "grid")]
+mod grid;
+mod grouped;
+mod heatmap;
+mod iter;
+#[cfg(feature = "serde")]
+mod json;
+#[cfg(feature = "config")]
+mod load;
+mod lookup;
+mod macros;
 mod matcher;
+#[cfg(feature = "rayon")]
+mod par;
+mod periodic;
+mod radix;
+#[cfg(feature = "std")]
+mod reader;
+mod rule;
+mod search;
+#[cfg(feature = "words")]
+mod spoken;
+mod stateful;
+mod stats;
+#[cfg(all(test, feature = "std"))]
+mod test_util;
+mod validate;
+#[cfg(feature = "wasm")]
+mod wasm;
 
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "tokio")]
+pub use async_io::*;
+pub use cache::*;
+pub use classify::*;
+#[cfg(feature = "color")]
+pub use color::*;
+pub use config::*;
+pub use csv::*;
+pub use digits::*;
+pub use generic::*;
+#[cfg(feature = "grid")]
+pub use grid::*;
+pub use grouped::*;
+pub use heatmap::*;
+pub use iter::*;
+#[cfg(feature = "serde")]
+pub use json::*;
+#[cfg(feature = "config")]
+pub use load::*;
+pub use lookup::*;
 pub use matcher::*;
+#[cfg(feature = "rayon")]
+pub use par::*;
+pub use periodic::*;
+pub use radix::*;
+#[cfg(feature = "std")]
+pub use reader::*;
+pub use rule::*;
+pub use search::*;
+#[cfg(feature = "words")]
+pub use spoken::*;
+pub use stateful::*;
+pub use stats::*;
+#[cfg(feature = "std")]
+use std::io::{self, BufWriter, StdoutLock, Write};
 use thiserror::Error;
+pub use validate::*;
+#[cfg(feature = "wasm")]
+pub use wasm::*;
 
 /// Provides a configurable version of FizzBuzz.
 ///
+/// The output is written to stdout. Use `fizzbuzz_to()` to write it somewhere else.
+///
 /// # Parameters
 /// With `from` and `to` you can define the number area for which to run the operation. Both of
 /// these values are inclusive. This means that if you specify 1 and 10, the numbers for which
 /// output is produced are: 1,2,3,4,5,6,7,8,9,10
 ///
 /// With `matchers` you can provide some `matcher::Matcher`s. These are used to configure how
-/// numbers are substituted with words. The matchers are tested in the order of the vector.
+/// numbers are substituted with words. The matchers are tested in the order of the slice.
 ///
 /// # Errors
 /// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
 ///   `to` parameters value.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
 ///
 /// # Example
 /// ```
@@ -77,130 +169,1936 @@ use thiserror::Error;
 ///
 /// assert!(fizzbuzz(1, 10, &matchers).is_ok());
 /// assert!(fizzbuzz(10, 1, &matchers).is_err());
+///
+/// // Arrays and slices work as well.
+/// let fizz = Matcher::new(3, "Fizz").unwrap();
+/// assert!(fizzbuzz(1, 10, &[fizz]).is_ok());
+/// assert!(fizzbuzz(1, 10, &matchers[..1]).is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz(from: usize, to: usize, matchers: &[Matcher]) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_to(out, from, to, matchers))
+}
+
+/// Provides a configurable version of FizzBuzz, that writes to an arbitrary writer.
+///
+/// Every line is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_to, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// let mut out = Vec::new();
+/// fizzbuzz_to(&mut out, 1, 5, &matchers).unwrap();
+///
+/// assert_eq!(out, b"1\n2\nFizz\n4\nBuzz\n");
 /// ```
-pub fn fizzbuzz(from: usize, to: usize, matchers: &Vec<Matcher>) -> Result<(), FizzBuzzError> {
+#[cfg(feature = "std")]
+pub fn fizzbuzz_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<(), FizzBuzzError> {
     if from > to {
         return Err(FizzBuzzError::FromBiggerThanTo { from, to });
     }
 
-    for i in from..(to + 1) {
-        println!("{}", line(i, matchers));
-    }
+    write_lines(writer, from..=to, matchers, None)?;
 
     Ok(())
 }
 
-/// Provides a configurable version of FizzBuzz for a single number.
+/// A flush interval for `fizzbuzz_flushed()`, that keeps most of the benefit of buffering.
+pub const DEFAULT_FLUSH_INTERVAL: usize = 4096;
+
+/// Provides a configurable version of FizzBuzz, that regularly flushes its output.
+///
+/// The output is written to stdout. Use `fizzbuzz_flushed_to()` to write it somewhere else.
+///
+/// `fizzbuzz()` buffers its output, and only flushes it at the very end. When piping the output
+/// into another program, like `head`, that program doesn't see any lines until the buffer is full.
+/// This function flushes stdout every `interval` lines instead, so the output arrives in smaller
+/// batches.
 ///
 /// # Parameters
-/// With `number`, you can provide the number to calculate the result for.
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
 ///
-/// With `matchers` you can provide some `matcher::Matcher`s. These are used to configure how
-/// numbers are substituted with words. The matchers are tested in the order of the vector.
+/// With `interval` you can define after how many lines the output is flushed. Smaller values make
+/// the output arrive sooner, larger values make printing faster. `DEFAULT_FLUSH_INTERVAL` is a
+/// reasonable default.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+/// - Returns `FizzBuzzError::IntervalIsZero`, if the `interval` parameter is 0.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
 ///
 /// # Example
 /// ```
-/// use extended_fizzbuzz::{line, Matcher};
+/// use extended_fizzbuzz::{fizzbuzz_flushed, Matcher, DEFAULT_FLUSH_INTERVAL};
 ///
-/// let matchers = vec![
-///     Matcher::new(3, "Fizz").unwrap(),
-///     Matcher::new(5, "Buzz").unwrap(),
-/// ];
+/// let matchers = Matcher::classic();
 ///
-/// assert_eq!(line(1, &matchers), "1".to_string());
-/// assert_eq!(line(3, &matchers), "Fizz".to_string());
-/// assert_eq!(line(5, &matchers), "Buzz".to_string());
-/// assert_eq!(line(15, &matchers), "FizzBuzz".to_string());
-/// assert_eq!(line(16, &matchers), "16".to_string());
+/// assert!(fizzbuzz_flushed(1, 10, &matchers, DEFAULT_FLUSH_INTERVAL).is_ok());
+/// assert!(fizzbuzz_flushed(1, 10, &matchers, 0).is_err());
 /// ```
-pub fn line(number: usize, matchers: &Vec<Matcher>) -> String {
-    let mut out = String::new();
+#[cfg(feature = "std")]
+pub fn fizzbuzz_flushed(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    interval: usize,
+) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_flushed_to(out, from, to, matchers, interval))
+}
 
-    for m in matchers.iter() {
-        out += m.text(number);
+/// Provides a configurable version of FizzBuzz, that writes to an arbitrary writer and regularly
+/// flushes it.
+///
+/// This works the same way as `fizzbuzz_to()`, but `writer` is flushed after every `interval`
+/// lines, and once more at the end. This is only useful for buffered writers, like a `BufWriter`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to`, `matchers` and `interval` parameters work the same way as for
+/// `fizzbuzz_flushed()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::IntervalIsZero`, if the `interval` parameter is 0. Nothing is
+///   written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to or flushing the `writer` fails. Failed flushes
+///   don't belong to a single number, so `number` is `None` for them.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_flushed_to, Matcher};
+/// use std::io::BufWriter;
+///
+/// let mut out = BufWriter::new(Vec::new());
+/// fizzbuzz_flushed_to(&mut out, 1, 5, &Matcher::classic(), 2).unwrap();
+///
+/// assert_eq!(out.get_ref(), b"1\n2\nFizz\n4\nBuzz\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_flushed_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    interval: usize,
+) -> Result<(), FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
     }
-
-    if out.is_empty() {
-        out += &number.to_string();
+    if interval == 0 {
+        return Err(FizzBuzzError::IntervalIsZero);
     }
 
-    out
+    write_lines(writer, from..=to, matchers, Some(interval))
 }
 
-/// All errors the `fizzbuzz()` function can produce.
-#[derive(Error, Debug)]
-#[non_exhaustive]
-pub enum FizzBuzzError {
-    /// The `from` parameter has a higher value than the `to` parameter. No valid range can be
-    /// constructed.
-    #[error("`from` value ({from}) is bigger than `to` value({to})")]
-    FromBiggerThanTo { from: usize, to: usize },
+/// Provides a configurable version of FizzBuzz, that returns the number of lines it printed.
+///
+/// The output is written to stdout. Use `fizzbuzz_run_to()` to write it somewhere else.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_run, Matcher};
+///
+/// assert_eq!(fizzbuzz_run(1, 10, &Matcher::classic()).unwrap(), 10);
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_run(from: usize, to: usize, matchers: &[Matcher]) -> Result<usize, FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_run_to(out, from, to, matchers))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rand::random;
+/// Provides a configurable version of FizzBuzz, that writes to an arbitrary writer and returns
+/// the number of lines it wrote.
+///
+/// Every line is terminated by a `\n`. The range from 0 to `usize::MAX` contains one number more
+/// than a `usize` can count, so `usize::MAX` is returned for it.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_run_to, Matcher};
+///
+/// let mut out = Vec::new();
+///
+/// assert_eq!(fizzbuzz_run_to(&mut out, 1, 5, &Matcher::classic()).unwrap(), 5);
+/// assert_eq!(out, b"1\n2\nFizz\n4\nBuzz\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_run_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<usize, FizzBuzzError> {
+    fizzbuzz_to(writer, from, to, matchers)?;
 
-    #[test]
-    fn line_normal() {
-        let text1 = "Fizz";
-        let text2 = "Buzz";
+    Ok((to - from).saturating_add(1))
+}
 
-        // u8 is used for rng, to ensure that the result of number1 * number2 is within the
-        // boundaries of usize and keep relatively short rng times.
-        let mut number1: u8 = 0;
-        let mut number2 = 0;
+/// Provides a configurable version of FizzBuzz, that counts down.
+///
+/// The output is written to stdout. Use `fizzbuzz_rev_to()` to write it somewhere else.
+///
+/// # Parameters
+/// With `from` and `to` you can define the number area for which to run the operation. Both of
+/// these values are inclusive. Other than for `fizzbuzz()`, `from` is the upper end of the range.
+/// This means that if you specify 10 and 1, the numbers for which output is produced are:
+/// 10,9,8,7,6,5,4,3,2,1
+///
+/// The `matchers` parameter works the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromSmallerThanTo`, if the `from` parameters value is smaller than
+///   the `to` parameters value.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_rev, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// assert!(fizzbuzz_rev(10, 1, &matchers).is_ok());
+/// assert!(fizzbuzz_rev(1, 10, &matchers).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_rev(from: usize, to: usize, matchers: &[Matcher]) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_rev_to(out, from, to, matchers))
+}
 
-        while number1 == 0 || number1 == 1 || number1 >= (u8::MAX / 2) {
-            number1 = random();
-        }
-        while number2 < number1 || number2 % number1 == 0 {
-            number2 = random();
-        }
+/// Provides a configurable version of FizzBuzz, that counts down and writes to an arbitrary
+/// writer.
+///
+/// Every line is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz_rev()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromSmallerThanTo`, if the `from` parameters value is smaller than
+///   the `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_rev_to, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// let mut out = Vec::new();
+/// fizzbuzz_rev_to(&mut out, 5, 1, &matchers).unwrap();
+///
+/// assert_eq!(out, b"Buzz\n4\nFizz\n2\n1\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_rev_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<(), FizzBuzzError> {
+    if from < to {
+        return Err(FizzBuzzError::FromSmallerThanTo { from, to });
+    }
 
-        let number1 = number1.into();
-        let number2 = number2.into();
+    write_lines(writer, (to..=from).rev(), matchers, None)?;
 
-        let matchers = vec![
-            Matcher::new(number1, text1).unwrap(),
-            Matcher::new(number2, text2).unwrap(),
-        ];
+    Ok(())
+}
 
-        assert_eq!(line(1, &matchers), "1");
-        assert_eq!(line(number1, &matchers), text1.to_string());
-        assert_eq!(line(number2, &matchers), text2.to_string());
-        assert_eq!(
-            line(number1 * number2, &matchers),
-            format!("{}{}", text1, text2)
-        );
-        assert_eq!(
-            line((number1 * number2) + 1, &matchers),
-            ((number1 * number2) + 1).to_string()
-        );
-    }
+/// Provides a configurable version of FizzBuzz, that skips numbers.
+///
+/// The output is written to stdout. Use `fizzbuzz_step_to()` to write it somewhere else.
+///
+/// # Parameters
+/// With `from` and `to` you can define the number area for which to run the operation. Both of
+/// these values are inclusive.
+///
+/// With `step` you can define the distance between two numbers. This means that if you specify 1,
+/// 10 and 3, the numbers for which output is produced are: 1,4,7,10
+///
+/// The `matchers` parameter works the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+/// - Returns `FizzBuzzError::StepIsZero`, if the `step` parameter is 0.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_step, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// assert!(fizzbuzz_step(1, 10, 2, &matchers).is_ok());
+/// assert!(fizzbuzz_step(1, 10, 0, &matchers).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_step(
+    from: usize,
+    to: usize,
+    step: usize,
+    matchers: &[Matcher],
+) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_step_to(out, from, to, step, matchers))
+}
 
-    #[test]
-    fn line_one() {
-        let text1 = "Fizz";
-        let text2 = "Buzz";
+/// Provides a configurable version of FizzBuzz, that skips numbers and writes to an arbitrary
+/// writer.
+///
+/// Every line is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to`, `step` and `matchers` parameters work the same way as for
+/// `fizzbuzz_step()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::StepIsZero`, if the `step` parameter is 0. Nothing is written in
+///   that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_step_to, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// let mut out = Vec::new();
+/// fizzbuzz_step_to(&mut out, 1, 10, 3, &matchers).unwrap();
+///
+/// assert_eq!(out, b"1\n4\n7\nBuzz\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_step_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    step: usize,
+    matchers: &[Matcher],
+) -> Result<(), FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+    if step == 0 {
+        return Err(FizzBuzzError::StepIsZero);
+    }
 
-        let number1 = 1;
-        let mut number2: u8 = 0;
-        while number2 < number1 {
-            number2 = random();
-        }
+    // Stepping past `usize::MAX` ends the range just like stepping past `to`.
+    let numbers = core::iter::successors(Some(from), |i| i.checked_add(step).filter(|n| *n <= to));
+    write_lines(writer, numbers, matchers, None)?;
 
-        let number1 = number1.into();
-        let number2 = number2.into();
+    Ok(())
+}
 
-        let matchers = vec![
-            Matcher::new(number1, text1).unwrap(),
-            Matcher::new(number2, text2).unwrap(),
-        ];
+/// Provides a configurable version of FizzBuzz, for arbitrary numbers.
+///
+/// The output is written to stdout. Use `fizzbuzz_each_to()` to write it somewhere else.
+///
+/// # Parameters
+/// With `numbers` you can provide the numbers for which to run the operation. They are processed
+/// in the order they are yielded, and may repeat.
+///
+/// The `matchers` parameter works the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_each, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// assert!(fizzbuzz_each(vec![2, 4, 8, 16], &matchers).is_ok());
+/// assert!(fizzbuzz_each((1..=100).filter(|i| i % 7 == 0), &matchers).is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_each<I>(numbers: I, matchers: &[Matcher]) -> Result<(), FizzBuzzError>
+where
+    I: IntoIterator<Item = usize>,
+{
+    with_stdout(|out| fizzbuzz_each_to(out, numbers, matchers))
+}
 
-        assert_eq!(line(1, &matchers), text1.to_string());
-        assert_eq!(line(number2, &matchers), format!("{}{}", text1, text2));
-        assert_eq!(line((number1 * number2) + 1, &matchers), text1.to_string());
+/// Provides a configurable version of FizzBuzz, for arbitrary numbers and an arbitrary writer.
+///
+/// Every line is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `numbers` and `matchers` parameters work the same way as for `fizzbuzz_each()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_each_to, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// let mut out = Vec::new();
+/// fizzbuzz_each_to(&mut out, [10, 3, 7, 3], &matchers).unwrap();
+///
+/// assert_eq!(out, b"Buzz\nFizz\n7\nFizz\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_each_to<W, I>(
+    writer: &mut W,
+    numbers: I,
+    matchers: &[Matcher],
+) -> Result<(), FizzBuzzError>
+where
+    W: Write,
+    I: IntoIterator<Item = usize>,
+{
+    write_lines(writer, numbers, matchers, None)?;
+
+    Ok(())
+}
+
+/// Provides a configurable version of FizzBuzz, that returns the output instead of printing it.
+///
+/// The returned vector contains one `String` per number in the range, in ascending order.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Memory
+/// All lines are kept in memory at once. For very large ranges this can exhaust the available
+/// memory, so prefer `fizzbuzz_to()` if the output only needs to be streamed somewhere. Use
+/// `fizzbuzz_lines_limited()` if the range comes from an untrusted source.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_lines, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// assert_eq!(
+///     fizzbuzz_lines(1, 5, &matchers).unwrap(),
+///     vec!["1", "2", "Fizz", "4", "Buzz"]
+/// );
+/// assert!(fizzbuzz_lines(5, 1, &matchers).is_err());
+/// ```
+pub fn fizzbuzz_lines(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<Vec<String>, FizzBuzzError> {
+    Ok(fizzbuzz_iter(from, to, matchers)?.collect())
+}
+
+/// Provides a configurable version of FizzBuzz, that returns the output for a limited range.
+///
+/// This works the same way as `fizzbuzz_lines()`, but fails instead of producing more than
+/// `limit` lines. This keeps huge ranges from exhausting the available memory.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// With `limit` you can provide the maximum number of lines to produce.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+/// - Returns `FizzBuzzError::RangeTooLarge`, if the range contains more than `limit` numbers.
+///   Nothing is allocated in that case.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_lines_limited, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(
+///     fizzbuzz_lines_limited(1, 3, &matchers, 3).unwrap(),
+///     vec!["1", "2", "Fizz"]
+/// );
+/// assert!(fizzbuzz_lines_limited(1, 4, &matchers, 3).is_err());
+/// assert!(fizzbuzz_lines_limited(1, usize::MAX, &matchers, 1000).is_err());
+/// ```
+pub fn fizzbuzz_lines_limited(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    limit: usize,
+) -> Result<Vec<String>, FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let requested = (to - from).saturating_add(1);
+    if requested > limit {
+        return Err(FizzBuzzError::RangeTooLarge { requested, limit });
+    }
+
+    fizzbuzz_lines(from, to, matchers)
+}
+
+/// Provides a configurable version of FizzBuzz, that stores the output in an existing vector.
+///
+/// This works the same way as `fizzbuzz_lines()`, but the lines replace the contents of `out`.
+/// The vector and the `String`s already in it are reused, so repeated calls with ranges of
+/// similar size don't allocate.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// With `out` you can provide the vector to store the lines in.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. `out` isn't changed in that case.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_collect_into, Matcher};
+///
+/// let matchers = Matcher::classic();
+/// let mut out = Vec::new();
+///
+/// fizzbuzz_collect_into(1, 5, &matchers, &mut out).unwrap();
+/// assert_eq!(out, vec!["1", "2", "Fizz", "4", "Buzz"]);
+///
+/// fizzbuzz_collect_into(14, 15, &matchers, &mut out).unwrap();
+/// assert_eq!(out, vec!["14", "FizzBuzz"]);
+/// ```
+pub fn fizzbuzz_collect_into(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    out: &mut Vec<String>,
+) -> Result<(), FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let mut len = 0;
+    for number in from..=to {
+        match out.get_mut(len) {
+            Some(buf) => line_into(number, matchers, buf),
+            None => out.push(line(number, matchers)),
+        }
+        len += 1;
+    }
+    out.truncate(len);
+
+    Ok(())
+}
+
+/// Provides a configurable version of FizzBuzz, that passes every line to a closure.
+///
+/// The closure `f` is called with every number in the range and its line, in ascending order. The
+/// lines are built in a single reused buffer, so no memory is allocated per line.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. The closure isn't called in that case.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_for_each, Matcher};
+///
+/// let mut fizzes = 0;
+/// fizzbuzz_for_each(1, 15, &Matcher::classic(), |_, line| {
+///     if line.contains("Fizz") {
+///         fizzes += 1;
+///     }
+/// })
+/// .unwrap();
+///
+/// assert_eq!(fizzes, 5);
+/// ```
+pub fn fizzbuzz_for_each<F>(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+    mut f: F,
+) -> Result<(), FizzBuzzError>
+where
+    F: FnMut(usize, &str),
+{
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let mut buf = String::new();
+    for i in from..=to {
+        line_into(i, matchers, &mut buf);
+        f(i, &buf);
+    }
+
+    Ok(())
+}
+
+/// Provides a configurable version of FizzBuzz for a single number.
+///
+/// # Parameters
+/// With `number`, you can provide the number to calculate the result for.
+///
+/// With `matchers` you can provide some `matcher::Matcher`s. These are used to configure how
+/// numbers are substituted with words. The matchers are tested in the order of the slice. The
+/// number itself is only returned if no matcher matches, so a matcher with an empty word hides
+/// the numbers it matches.
+///
+/// # Zero
+/// 0 is divisible by every number, so every matcher created by `Matcher::new()` matches it, and
+/// `line(0, ..)` concatenates the words of all of them. Use `FizzBuzzConfigBuilder::skip_zero()`
+/// to leave 0 out instead.
+///
+/// ```
+/// use extended_fizzbuzz::{line, Matcher};
+///
+/// assert_eq!(line(0, &Matcher::classic()), "FizzBuzz");
+/// ```
+///
+/// # Order
+/// The words of all matching matchers are concatenated strictly in the order of the slice. This
+/// is guaranteed, so reordering the matchers is a reliable way to reorder the words. All other
+/// functions producing lines from multiple words, like `line_with()` or `fizzbuzz()`, follow the
+/// same order.
+///
+/// ```
+/// use extended_fizzbuzz::{line, Matcher};
+///
+/// let matchers = vec![Matcher::buzz(), Matcher::fizz()];
+///
+/// assert_eq!(line(15, &matchers), "BuzzFizz");
+/// ```
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// assert_eq!(line(1, &matchers), "1".to_string());
+/// assert_eq!(line(3, &matchers), "Fizz".to_string());
+/// assert_eq!(line(5, &matchers), "Buzz".to_string());
+/// assert_eq!(line(15, &matchers), "FizzBuzz".to_string());
+/// assert_eq!(line(16, &matchers), "16".to_string());
+///
+/// // Arrays and slices work as well.
+/// let buzz = Matcher::new(5, "Buzz").unwrap();
+/// assert_eq!(line(10, &[buzz]), "Buzz".to_string());
+/// assert_eq!(line(15, &matchers[..1]), "Fizz".to_string());
+/// ```
+pub fn line(number: usize, matchers: &[Matcher]) -> String {
+    line_with(number, matchers, "")
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that separates the words.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// With `separator` you can provide a text, that is put between the words of two matching
+/// matchers. It is only used if more than one matcher matches.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_with, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(line_with(3, &matchers, "-"), "Fizz");
+/// assert_eq!(line_with(7, &matchers, "-"), "7");
+/// assert_eq!(line_with(15, &matchers, "-"), "Fizz-Buzz");
+/// assert_eq!(line_with(15, &matchers, " "), "Fizz Buzz");
+/// ```
+pub fn line_with(number: usize, matchers: &[Matcher], separator: &str) -> String {
+    let mut out = String::new();
+    push_line(number, matchers, separator, &mut out);
+    out
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, with a custom fallback.
+///
+/// This works the same way as `line()`, but if no matcher matches, the result is created by
+/// calling `fallback` with the number. `line()` behaves like `line_fmt()` with a `fallback` of
+/// `|n| n.to_string()`.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_fmt, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(line_fmt(7, &matchers, |n| format!("[{}]", n)), "[7]");
+/// assert_eq!(line_fmt(15, &matchers, |n| format!("[{}]", n)), "FizzBuzz");
+/// ```
+pub fn line_fmt<F>(number: usize, matchers: &[Matcher], fallback: F) -> String
+where
+    F: FnOnce(usize) -> String,
+{
+    let mut out = String::new();
+
+    if push_words(number, matchers, "", &mut out) {
+        out
+    } else {
+        fallback(number)
+    }
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that reuses a buffer.
+///
+/// This works the same way as `line()`, but the result is written into `buf`, which is cleared
+/// first. If `buf` has enough capacity, no memory is allocated.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_into, Matcher};
+///
+/// let matchers = Matcher::classic();
+/// let mut buf = String::new();
+///
+/// line_into(15, &matchers, &mut buf);
+/// assert_eq!(buf, "FizzBuzz");
+///
+/// line_into(7, &matchers, &mut buf);
+/// assert_eq!(buf, "7");
+/// ```
+pub fn line_into(number: usize, matchers: &[Matcher], buf: &mut String) {
+    buf.clear();
+    push_line(number, matchers, "", buf);
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that writes to an arbitrary
+/// writer.
+///
+/// The result of `line()` is written to `writer`, followed by a `\n`.
+///
+/// Returns the number of bytes written, including the `\n`.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// With `writer` you can provide the destination of the output.
+///
+/// # Errors
+/// Returns all errors that occur while writing to the `writer`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{write_line, Matcher};
+///
+/// let matchers = Matcher::classic();
+/// let mut out = Vec::new();
+///
+/// assert_eq!(write_line(15, &matchers, &mut out).unwrap(), 9);
+/// assert_eq!(write_line(7, &matchers, &mut out).unwrap(), 2);
+/// assert_eq!(out, b"FizzBuzz\n7\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn write_line<W: Write>(
+    number: usize,
+    matchers: &[Matcher],
+    writer: &mut W,
+) -> io::Result<usize> {
+    let mut buf = String::new();
+    push_line(number, matchers, "", &mut buf);
+    buf.push('\n');
+
+    writer.write_all(buf.as_bytes())?;
+    Ok(buf.len())
+}
+
+/// Format a number the way `line()` does, if no matcher matches it.
+///
+/// This is useful if it is already known that no matcher matches, and avoids checking the
+/// matchers again.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::fallback_string;
+///
+/// assert_eq!(fallback_string(7), "7");
+/// assert_eq!(fallback_string(1234), 1234.to_string());
+/// ```
+pub fn fallback_string(number: usize) -> String {
+    let mut out = String::new();
+    push_number(number, &mut out);
+    out
+}
+
+/// Append the result of `line_with()` to `out`.
+fn push_line(number: usize, matchers: &[Matcher], separator: &str, out: &mut String) {
+    if !push_words(number, matchers, separator, out) {
+        push_number(number, out);
+    }
+}
+
+/// Append the words of all matchers matching `number` to `out`, separated by `separator`.
+///
+/// Returns wether any matcher matched.
+fn push_words(number: usize, matchers: &[Matcher], separator: &str, out: &mut String) -> bool {
+    let mut matched = false;
+
+    // The words have to be appended in the order of the matchers, see the docs of `line()`.
+    for word in matchers.iter().filter_map(|m| m.text_opt(number)) {
+        if matched {
+            out.push_str(separator);
+        }
+        out.push_str(word);
+        matched = true;
+    }
+
+    matched
+}
+
+/// Append the decimal representation of `number` to `out`.
+///
+/// The digits are formatted into a buffer on the stack, so the only allocation this can cause is
+/// `out` growing.
+fn push_number(mut number: usize, out: &mut String) {
+    // Enough digits for a 128 bit `usize`.
+    let mut digits = [0u8; 39];
+    let mut start = digits.len();
+
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (number % 10) as u8;
+        number /= 10;
+
+        if number == 0 {
+            break;
+        }
+    }
+
+    // Only ASCII digits were written.
+    out.push_str(core::str::from_utf8(&digits[start..]).unwrap());
+}
+
+/// Write the result of `line()` for every number in `numbers` to `writer`, each followed by a
+/// `\n`.
+///
+/// If writing fails, the returned error contains the number whose line couldn't be written.
+///
+/// If `flush_interval` is set, `writer` is flushed after every `flush_interval` lines, and once
+/// more at the end. Failed flushes don't belong to a single number. The interval mustn't be 0.
+#[cfg(feature = "std")]
+fn write_lines<W, I>(
+    writer: &mut W,
+    numbers: I,
+    matchers: &[Matcher],
+    flush_interval: Option<usize>,
+) -> Result<(), FizzBuzzError>
+where
+    W: Write,
+    I: IntoIterator<Item = usize>,
+{
+    let mut buf = String::new();
+
+    for (index, i) in numbers.into_iter().enumerate() {
+        line_into(i, matchers, &mut buf);
+        buf.push('\n');
+        writer
+            .write_all(buf.as_bytes())
+            .map_err(FizzBuzzError::io_at(i))?;
+
+        if flush_interval.is_some_and(|interval| (index + 1) % interval == 0) {
+            writer.flush()?;
+        }
+    }
+    if flush_interval.is_some() {
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Provides a version of FizzBuzz for a single number, where only the first matching matcher
+/// counts.
+///
+/// Other than `line()`, this doesn't concatenate the words of all matching matchers. Instead, the
+/// matchers are treated as a priority list, and only the word of the first matching one is used.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_first, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::exactly(15, "Jackpot"),
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+/// ];
+///
+/// assert_eq!(line_first(15, &matchers), "Jackpot");
+/// assert_eq!(line_first(30, &matchers), "Fizz");
+/// assert_eq!(line_first(7, &matchers), "7");
+/// ```
+pub fn line_first(number: usize, matchers: &[Matcher]) -> String {
+    match matchers.iter().find(|m| m.matches(number)) {
+        Some(m) => m.word().to_owned(),
+        None => fallback_string(number),
+    }
+}
+
+/// Run `f` with a buffered handle to stdout, and flush it afterwards.
+///
+/// Stdout is line buffered, so writing to it directly issues a write for every single line.
+/// Buffering the output instead speeds up printing large ranges considerably (1,000,000 lines to
+/// `/dev/null`: about 240ms line buffered, about 65ms buffered).
+#[cfg(feature = "std")]
+pub(crate) fn with_stdout<T, F>(f: F) -> Result<T, FizzBuzzError>
+where
+    F: FnOnce(&mut BufWriter<StdoutLock<'_>>) -> Result<T, FizzBuzzError>,
+{
+    let mut out = BufWriter::new(io::stdout().lock());
+    let result = f(&mut out)?;
+    out.flush()?;
+
+    Ok(result)
+}
+
+/// All errors the `fizzbuzz()` family of functions can produce.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum FizzBuzzError {
+    /// The `from` parameter has a higher value than the `to` parameter. No valid range can be
+    /// constructed.
+    #[error("`from` value ({from}) is bigger than `to` value({to})")]
+    FromBiggerThanTo { from: usize, to: usize },
+
+    /// The `from` parameter of a signed operation has a higher value than the `to` parameter. No
+    /// valid range can be constructed.
+    #[error("`from` value ({from}) is bigger than `to` value({to})")]
+    SignedFromBiggerThanTo { from: i64, to: i64 },
+
+    /// The `from` parameter of a descending operation has a lower value than the `to` parameter.
+    /// No valid range can be constructed.
+    #[error("`from` value ({from}) is smaller than `to` value({to})")]
+    FromSmallerThanTo { from: usize, to: usize },
+
+    /// The `step` parameter was set to 0. The operation would never advance past its first
+    /// number.
+    #[error("`step` is 0, but the range can't advance by 0")]
+    StepIsZero,
+
+    /// The `columns` parameter of a grid operation was set to 0. No cell could ever be placed.
+    #[error("`columns` is 0, but a grid needs at least one column")]
+    ColumnsIsZero,
+
+    /// The `interval` parameter of a flushing operation was set to 0. The output would have to be
+    /// flushed before the first line.
+    #[error("`interval` is 0, but at least one line has to be written between flushes")]
+    IntervalIsZero,
+
+    /// The range contains more numbers than the limit allows. `requested` is the number of
+    /// numbers in the range, saturated at `usize::MAX`.
+    #[error("the range contains {requested} numbers, but at most {limit} are allowed")]
+    RangeTooLarge { requested: usize, limit: usize },
+
+    /// A matcher uses 1 as its divisor. It substitutes every number, which is usually a mistake.
+    #[error("the matcher for `{word}` uses the divisor 1 and matches every number")]
+    DivisorIsOne { word: String },
+
+    /// Two matchers use the same divisor. The output contains the words of both of them for
+    /// every multiple of it, which is usually a mistake.
+    #[error("multiple matchers use the divisor {number}")]
+    DuplicateDivisor { number: usize },
+
+    /// Writing the output failed, e.g. because of a broken pipe or a full disk. Contains the
+    /// error reported by the writer, and the number whose line couldn't be written. The `number`
+    /// is `None`, if the failed write doesn't belong to the line of a single number, e.g. when
+    /// flushing the output.
+    #[cfg(feature = "std")]
+    #[error("write failed: {source}")]
+    Io {
+        number: Option<usize>,
+        source: io::Error,
+    },
+}
+
+#[cfg(feature = "std")]
+impl FizzBuzzError {
+    /// Create a function wrapping an `io::Error` that occurred while writing the line of `number`.
+    pub(crate) fn io_at(number: usize) -> impl FnOnce(io::Error) -> Self {
+        move |source| FizzBuzzError::Io {
+            number: Some(number),
+            source,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for FizzBuzzError {
+    fn from(source: io::Error) -> Self {
+        FizzBuzzError::Io {
+            number: None,
+            source,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::test_util::FailingWriter;
+    use rand::random;
+
+    /// A writer that records every write, and the number of bytes written before every flush.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Default)]
+    struct RecordingWriter {
+        writes: Vec<Vec<u8>>,
+        flushes: Vec<usize>,
+    }
+
+    #[cfg(feature = "std")]
+    impl Write for RecordingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.writes.push(buf.to_vec());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushes.push(self.writes.iter().map(Vec::len).sum());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn line_normal() {
+        let text1 = "Fizz";
+        let text2 = "Buzz";
+
+        // u8 is used for rng, to ensure that the result of number1 * number2 is within the
+        // boundaries of usize and keep relatively short rng times.
+        let mut number1: u8 = 0;
+        let mut number2 = 0;
+
+        while number1 == 0 || number1 == 1 || number1 >= (u8::MAX / 2) {
+            number1 = random();
+        }
+        while number2 < number1 || number2 % number1 == 0 {
+            number2 = random();
+        }
+
+        let number1 = number1.into();
+        let number2 = number2.into();
+
+        let matchers = vec![
+            Matcher::new(number1, text1).unwrap(),
+            Matcher::new(number2, text2).unwrap(),
+        ];
+
+        assert_eq!(line(1, &matchers), "1");
+        assert_eq!(line(number1, &matchers), text1.to_string());
+        assert_eq!(line(number2, &matchers), text2.to_string());
+        assert_eq!(
+            line(number1 * number2, &matchers),
+            format!("{}{}", text1, text2)
+        );
+        assert_eq!(
+            line((number1 * number2) + 1, &matchers),
+            ((number1 * number2) + 1).to_string()
+        );
+    }
+
+    #[test]
+    fn line_zero() {
+        assert_eq!(line(0, &Matcher::classic()), "FizzBuzz");
+        assert_eq!(line(0, &[Matcher::buzz(), Matcher::fizz()]), "BuzzFizz");
+        assert_eq!(line(0, &[]), "0");
+
+        // Only divisibility matchers match 0, others decide on their own.
+        let matchers = vec![
+            Matcher::fizz(),
+            Matcher::exactly(1, "One"),
+            Matcher::with_remainder(2, 1, "Odd").unwrap(),
+        ];
+        assert_eq!(line(0, &matchers), "Fizz");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_to_zero() {
+        let mut out = Vec::new();
+        fizzbuzz_to(&mut out, 0, 0, &Matcher::classic()).unwrap();
+        assert_eq!(out, b"FizzBuzz\n");
+
+        let mut out = Vec::new();
+        fizzbuzz_to(&mut out, 0, 3, &Matcher::classic()).unwrap();
+        assert_eq!(out, b"FizzBuzz\n1\n2\nFizz\n");
+
+        assert!(fizzbuzz(0, 0, &Matcher::classic()).is_ok());
+    }
+
+    #[test]
+    fn line_order_reversed() {
+        let matchers = vec![Matcher::buzz(), Matcher::fizz()];
+
+        assert_eq!(line(15, &matchers), "BuzzFizz");
+        assert_eq!(line(30, &matchers), "BuzzFizz");
+        assert_eq!(line_with(15, &matchers, "-"), "Buzz-Fizz");
+        assert_eq!(line(15, &Matcher::classic()), "FizzBuzz");
+    }
+
+    #[test]
+    fn line_order_slice() {
+        let matchers = vec![
+            Matcher::new(7, "Bazz").unwrap(),
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::exactly(105, "Answer"),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        assert_eq!(line(105, &matchers), "BazzFizzAnswerBuzz");
+        assert_eq!(line(210, &matchers), "BazzFizzBuzz");
+
+        let reversed: Vec<_> = matchers.iter().rev().cloned().collect();
+        assert_eq!(line(105, &reversed), "BuzzAnswerFizzBazz");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_to_order() {
+        let mut out = Vec::new();
+        fizzbuzz_to(&mut out, 14, 15, &[Matcher::buzz(), Matcher::fizz()]).unwrap();
+
+        assert_eq!(out, b"14\nBuzzFizz\n");
+    }
+
+    #[test]
+    fn line_one() {
+        let text1 = "Fizz";
+        let text2 = "Buzz";
+
+        let number1 = 1;
+        let mut number2: u8 = 0;
+        // number2 has to differ from number1 as well, otherwise 1 would be substituted by both
+        // words.
+        while number2 <= number1 {
+            number2 = random();
+        }
+
+        let number1 = number1.into();
+        let number2 = number2.into();
+
+        let matchers = vec![
+            Matcher::new(number1, text1).unwrap(),
+            Matcher::new(number2, text2).unwrap(),
+        ];
+
+        assert_eq!(line(1, &matchers), text1.to_string());
+        assert_eq!(line(number2, &matchers), format!("{}{}", text1, text2));
+        assert_eq!(line((number1 * number2) + 1, &matchers), text1.to_string());
+    }
+
+    #[test]
+    fn line_slice() {
+        let fizz = Matcher::new(3, "Fizz").unwrap();
+        let buzz = Matcher::new(5, "Buzz").unwrap();
+        let matchers = [fizz, buzz];
+
+        assert_eq!(line(15, &matchers), "FizzBuzz");
+        assert_eq!(line(15, &matchers[..1]), "Fizz");
+        assert_eq!(line(15, &matchers[1..]), "Buzz");
+        assert_eq!(line(15, &[]), "15");
+
+        let boxed: Box<[Matcher]> = vec![Matcher::new(7, "Bazz").unwrap()].into_boxed_slice();
+        assert_eq!(line(14, &boxed), "Bazz");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_max() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        assert!(fizzbuzz(usize::MAX - 2, usize::MAX, &matchers).is_ok());
+        assert!(fizzbuzz(usize::MAX, usize::MAX, &matchers).is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_to_normal() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        let mut out = Vec::new();
+        fizzbuzz_to(&mut out, 1, 15, &matchers).unwrap();
+
+        assert_eq!(
+            out,
+            b"1\n2\nFizz\n4\nBuzz\nFizz\n7\n8\nFizz\nBuzz\n11\nFizz\n13\n14\nFizzBuzz\n".to_vec()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_to_from_bigger_than_to() {
+        let matchers = vec![Matcher::new(3, "Fizz").unwrap()];
+
+        let mut out = Vec::new();
+        let res = fizzbuzz_to(&mut out, 10, 1, &matchers);
+
+        assert!(matches!(
+            res,
+            Err(FizzBuzzError::FromBiggerThanTo { from: 10, to: 1 })
+        ));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn fizzbuzz_lines_normal() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        let lines = fizzbuzz_lines(1, 15, &matchers).unwrap();
+
+        assert_eq!(lines.len(), 15);
+        assert_eq!(lines[0], "1");
+        assert_eq!(lines[2], "Fizz");
+        assert_eq!(lines[4], "Buzz");
+        assert_eq!(lines[14], "FizzBuzz");
+    }
+
+    #[test]
+    fn fizzbuzz_lines_single() {
+        let matchers = vec![Matcher::new(3, "Fizz").unwrap()];
+
+        assert_eq!(fizzbuzz_lines(3, 3, &matchers).unwrap(), vec!["Fizz"]);
+        assert_eq!(
+            fizzbuzz_lines(usize::MAX, usize::MAX, &matchers).unwrap(),
+            vec![line(usize::MAX, &matchers)]
+        );
+    }
+
+    #[test]
+    fn fizzbuzz_lines_from_bigger_than_to() {
+        let matchers = vec![Matcher::new(3, "Fizz").unwrap()];
+
+        assert!(matches!(
+            fizzbuzz_lines(2, 1, &matchers),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+
+    #[test]
+    fn fizzbuzz_lines_limited_normal() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(
+            fizzbuzz_lines_limited(1, 15, &matchers, 15).unwrap(),
+            fizzbuzz_lines(1, 15, &matchers).unwrap()
+        );
+        assert_eq!(
+            fizzbuzz_lines_limited(0, 0, &matchers, 1).unwrap(),
+            vec!["FizzBuzz"]
+        );
+        assert!(matches!(
+            fizzbuzz_lines_limited(1, 16, &matchers, 15),
+            Err(FizzBuzzError::RangeTooLarge {
+                requested: 16,
+                limit: 15
+            })
+        ));
+    }
+
+    #[test]
+    fn fizzbuzz_lines_limited_huge() {
+        let matchers = Matcher::classic();
+
+        assert!(matches!(
+            fizzbuzz_lines_limited(1, usize::MAX, &matchers, 1_000_000),
+            Err(FizzBuzzError::RangeTooLarge {
+                requested: usize::MAX,
+                limit: 1_000_000
+            })
+        ));
+        assert!(matches!(
+            fizzbuzz_lines_limited(0, usize::MAX, &matchers, usize::MAX - 1),
+            Err(FizzBuzzError::RangeTooLarge {
+                requested: usize::MAX,
+                ..
+            })
+        ));
+        assert!(matches!(
+            fizzbuzz_lines_limited(2, 1, &matchers, 0),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+
+    #[test]
+    fn fizzbuzz_collect_into_reused() {
+        let matchers = Matcher::classic();
+        let mut out = Vec::new();
+
+        fizzbuzz_collect_into(1, 15, &matchers, &mut out).unwrap();
+        assert_eq!(out, fizzbuzz_lines(1, 15, &matchers).unwrap());
+        let capacity = out.capacity();
+
+        fizzbuzz_collect_into(28, 31, &matchers, &mut out).unwrap();
+        assert_eq!(out, vec!["28", "29", "FizzBuzz", "31"]);
+        assert_eq!(out.capacity(), capacity);
+
+        fizzbuzz_collect_into(1, 20, &matchers, &mut out).unwrap();
+        assert_eq!(out, fizzbuzz_lines(1, 20, &matchers).unwrap());
+    }
+
+    #[test]
+    fn fizzbuzz_collect_into_from_bigger_than_to() {
+        let mut out = vec![String::from("kept")];
+
+        assert!(matches!(
+            fizzbuzz_collect_into(2, 1, &Matcher::classic(), &mut out),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+        assert_eq!(out, vec!["kept"]);
+    }
+
+    #[test]
+    fn fizzbuzz_for_each_collect() {
+        let matchers = Matcher::classic();
+        let mut calls = Vec::new();
+
+        fizzbuzz_for_each(1, 15, &matchers, |n, line| {
+            calls.push((n, line.to_string()))
+        })
+        .unwrap();
+
+        let expected: Vec<_> = (1..=15).map(|n| (n, line(n, &matchers))).collect();
+        assert_eq!(calls, expected);
+    }
+
+    #[test]
+    fn fizzbuzz_for_each_from_bigger_than_to() {
+        let mut called = false;
+
+        assert!(matches!(
+            fizzbuzz_for_each(2, 1, &Matcher::classic(), |_, _| called = true),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+        assert!(!called);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_flushed_to_every_line() {
+        let matchers = Matcher::classic();
+
+        let mut unbuffered = RecordingWriter::default();
+        fizzbuzz_to(&mut unbuffered, 1, 20, &matchers).unwrap();
+
+        let mut flushed = BufWriter::new(RecordingWriter::default());
+        fizzbuzz_flushed_to(&mut flushed, 1, 20, &matchers, 1).unwrap();
+        let flushed = flushed.into_inner().unwrap();
+
+        assert_eq!(flushed.writes, unbuffered.writes);
+        assert_eq!(flushed.writes.len(), 20);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_flushed_to_interval() {
+        let matchers = Matcher::classic();
+
+        let mut out = BufWriter::new(RecordingWriter::default());
+        fizzbuzz_flushed_to(&mut out, 1, 10, &matchers, 4).unwrap();
+        let out = out.into_inner().unwrap();
+
+        // Flushed after every 4 lines, and once more at the end.
+        assert_eq!(out.flushes, vec![11, 25, 35]);
+        assert_eq!(
+            out.writes.concat(),
+            b"1\n2\nFizz\n4\nBuzz\nFizz\n7\n8\nFizz\nBuzz\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_flushed_to_invalid() {
+        let mut out = RecordingWriter::default();
+
+        assert!(matches!(
+            fizzbuzz_flushed_to(&mut out, 1, 10, &Matcher::classic(), 0),
+            Err(FizzBuzzError::IntervalIsZero)
+        ));
+        assert!(matches!(
+            fizzbuzz_flushed_to(&mut out, 10, 1, &Matcher::classic(), 1),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 10, to: 1 })
+        ));
+        assert!(out.writes.is_empty());
+        assert!(out.flushes.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_flushed_to_flush_error() {
+        struct FailingFlush;
+
+        impl Write for FailingFlush {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Err(io::Error::new(io::ErrorKind::BrokenPipe, "failing flush"))
+            }
+        }
+
+        for interval in [2, 20] {
+            assert!(matches!(
+                fizzbuzz_flushed_to(&mut FailingFlush, 1, 10, &Matcher::classic(), interval),
+                Err(FizzBuzzError::Io { number: None, .. })
+            ));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_to_io_error() {
+        let matchers = vec![Matcher::new(3, "Fizz").unwrap()];
+
+        let mut writer = FailingWriter::new(2);
+        let res = fizzbuzz_to(&mut writer, 1, 10, &matchers);
+
+        match res {
+            Err(FizzBuzzError::Io { number, source }) => {
+                assert_eq!(number, Some(2));
+                assert_eq!(source.kind(), io::ErrorKind::BrokenPipe);
+            }
+            _ => panic!("expected an io error, got {:?}", res),
+        }
+        assert_eq!(writer.writes, 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_to_io_error_number() {
+        let matchers = Matcher::classic();
+
+        let mut writer = FailingWriter::new(5);
+        let res = fizzbuzz_to(&mut writer, 1, 10, &matchers);
+        assert!(matches!(
+            res,
+            Err(FizzBuzzError::Io {
+                number: Some(5),
+                ..
+            })
+        ));
+
+        let mut writer = FailingWriter::new(5);
+        let res = fizzbuzz_rev_to(&mut writer, 10, 1, &matchers);
+        assert!(matches!(
+            res,
+            Err(FizzBuzzError::Io {
+                number: Some(6),
+                ..
+            })
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_rev_to_normal() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        let mut out = Vec::new();
+        fizzbuzz_rev_to(&mut out, 15, 1, &matchers).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+
+        let mut expected = fizzbuzz_lines(1, 15, &matchers).unwrap();
+        expected.reverse();
+
+        assert_eq!(lines, expected);
+        assert_eq!(lines.first(), Some(&"FizzBuzz"));
+        assert_eq!(lines.last(), Some(&"1"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_rev_to_bounds() {
+        let matchers = vec![Matcher::new(3, "Fizz").unwrap()];
+
+        let mut out = Vec::new();
+        fizzbuzz_rev_to(&mut out, 7, 7, &matchers).unwrap();
+        assert_eq!(out, b"7\n");
+
+        let mut out = Vec::new();
+        fizzbuzz_rev_to(&mut out, 2, 0, &matchers).unwrap();
+        assert_eq!(out, b"2\n1\nFizz\n");
+
+        let mut out = Vec::new();
+        fizzbuzz_rev_to(&mut out, usize::MAX, usize::MAX - 1, &matchers).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_rev_to_from_smaller_than_to() {
+        let matchers = vec![Matcher::new(3, "Fizz").unwrap()];
+
+        let mut out = Vec::new();
+        let res = fizzbuzz_rev_to(&mut out, 1, 10, &matchers);
+
+        assert!(matches!(
+            res,
+            Err(FizzBuzzError::FromSmallerThanTo { from: 1, to: 10 })
+        ));
+        assert!(out.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_step_to_two() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        let mut out = Vec::new();
+        fizzbuzz_step_to(&mut out, 2, 12, 2, &matchers).unwrap();
+
+        assert_eq!(out, b"2\n4\nFizz\n8\nBuzz\nFizz\n");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_step_to_five() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        let mut out = Vec::new();
+        fizzbuzz_step_to(&mut out, 1, 23, 5, &matchers).unwrap();
+        assert_eq!(out, b"1\nFizz\n11\n16\nFizz\n");
+
+        let mut out = Vec::new();
+        fizzbuzz_step_to(&mut out, 5, 30, 5, &matchers).unwrap();
+        assert_eq!(out, b"Buzz\nBuzz\nFizzBuzz\nBuzz\nBuzz\nFizzBuzz\n");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_step_to_overflow() {
+        let matchers = vec![Matcher::new(3, "Fizz").unwrap()];
+
+        let mut out = Vec::new();
+        fizzbuzz_step_to(&mut out, usize::MAX - 3, usize::MAX, 2, &matchers).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 2);
+
+        let mut out = Vec::new();
+        fizzbuzz_step_to(&mut out, 0, usize::MAX, usize::MAX, &matchers).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_step_to_overflow_single() {
+        let mut out = Vec::new();
+        fizzbuzz_step_to(&mut out, usize::MAX - 1, usize::MAX, 5, &[]).unwrap();
+        assert_eq!(out, format!("{}\n", usize::MAX - 1).into_bytes());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_step_to_zero() {
+        let matchers = vec![Matcher::new(3, "Fizz").unwrap()];
+
+        let mut out = Vec::new();
+        let res = fizzbuzz_step_to(&mut out, 1, 10, 0, &matchers);
+
+        assert!(matches!(res, Err(FizzBuzzError::StepIsZero)));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn line_exactly() {
+        let mut matchers = Matcher::classic();
+        matchers.push(Matcher::exactly(42, "Answer"));
+
+        assert_eq!(line(42, &matchers), "FizzAnswer");
+        assert_eq!(line(45, &matchers), "FizzBuzz");
+        assert_eq!(line(41, &matchers), "41");
+
+        matchers.insert(0, Matcher::exactly(15, "Jackpot"));
+        assert_eq!(line(15, &matchers), "JackpotFizzBuzz");
+        assert_eq!(line(30, &matchers), "FizzBuzz");
+    }
+
+    #[test]
+    fn line_in_range() {
+        let mut matchers = vec![Matcher::in_range(10, 19, "Teen").unwrap()];
+        matchers.extend(Matcher::classic());
+
+        assert_eq!(line(9, &matchers), "Fizz");
+        assert_eq!(line(10, &matchers), "TeenBuzz");
+        assert_eq!(line(11, &matchers), "Teen");
+        assert_eq!(line(15, &matchers), "TeenFizzBuzz");
+        assert_eq!(line(19, &matchers), "Teen");
+        assert_eq!(line(20, &matchers), "Buzz");
+    }
+
+    #[test]
+    fn line_from_fn() {
+        let is_prime = |n: usize| n >= 2 && (2..n).all(|d| n % d != 0);
+        let matchers = vec![Matcher::from_fn("Prime", is_prime)];
+
+        let primes: Vec<_> = (1..=10)
+            .filter(|i| line(*i, &matchers) == "Prime")
+            .collect();
+        assert_eq!(primes, vec![2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn line_contains_digit() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::contains_digit(3, "Fizz").unwrap(),
+        ];
+
+        assert_eq!(line(3, &matchers), "FizzFizz");
+        assert_eq!(line(9, &matchers), "Fizz");
+        assert_eq!(line(13, &matchers), "Fizz");
+        assert_eq!(line(33, &matchers), "FizzFizz");
+        assert_eq!(line(14, &matchers), "14");
+    }
+
+    #[test]
+    fn line_with_separator() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_with(15, &matchers, "-"), "Fizz-Buzz");
+        assert_eq!(line_with(7, &matchers, "-"), "7");
+        assert_eq!(line_with(3, &matchers, "-"), "Fizz");
+        assert_eq!(line_with(5, &matchers, "-"), "Buzz");
+        assert_eq!(line_with(15, &matchers, ", "), "Fizz, Buzz");
+    }
+
+    #[test]
+    fn line_with_skips_unmatched() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(7, "Bazz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        assert_eq!(line_with(15, &matchers, "-"), "Fizz-Buzz");
+        assert_eq!(line_with(35, &matchers, "-"), "Bazz-Buzz");
+        assert_eq!(line_with(105, &matchers, "-"), "Fizz-Bazz-Buzz");
+    }
+
+    #[test]
+    fn line_with_empty_separator() {
+        let matchers = Matcher::classic();
+
+        for i in 0..100 {
+            assert_eq!(line_with(i, &matchers, ""), line(i, &matchers));
+        }
+    }
+
+    #[test]
+    fn line_first_jackpot() {
+        let mut matchers = vec![Matcher::exactly(15, "Jackpot")];
+        matchers.extend(Matcher::classic());
+
+        assert_eq!(line_first(15, &matchers), "Jackpot");
+        assert_eq!(line_first(3, &matchers), "Fizz");
+        assert_eq!(line_first(5, &matchers), "Buzz");
+        assert_eq!(line_first(7, &matchers), "7");
+
+        // Without the priority, both words are concatenated.
+        assert_eq!(line(15, &matchers), "JackpotFizzBuzz");
+    }
+
+    #[test]
+    fn line_first_order() {
+        let matchers = Matcher::classic();
+        assert_eq!(line_first(15, &matchers), "Fizz");
+
+        let matchers: Vec<_> = matchers.into_iter().rev().collect();
+        assert_eq!(line_first(15, &matchers), "Buzz");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_to_buffered() {
+        let mut matchers = Matcher::classic();
+        matchers.push(Matcher::exactly(42, "Answer"));
+
+        // This is what `fizzbuzz()` used to print with `println!()`.
+        let expected: String = (1..=1000)
+            .map(|i| format!("{}\n", line(i, &matchers)))
+            .collect();
+
+        let mut out = BufWriter::new(Vec::new());
+        fizzbuzz_to(&mut out, 1, 1000, &matchers).unwrap();
+        let out = out.into_inner().unwrap();
+
+        assert_eq!(out, expected.into_bytes());
+    }
+
+    #[test]
+    fn line_into_reuse() {
+        let mut matchers = Matcher::classic();
+        matchers.push(Matcher::exactly(42, "Answer"));
+
+        let mut buf = String::new();
+        for i in [15, 7, 42, 3, 100_000, 5, 1].iter() {
+            line_into(*i, &matchers, &mut buf);
+            assert_eq!(buf, line(*i, &matchers));
+        }
+    }
+
+    #[test]
+    fn line_into_capacity() {
+        let matchers = Matcher::classic();
+
+        let mut buf = String::with_capacity(32);
+        let capacity = buf.capacity();
+        for i in 0..1000 {
+            line_into(i, &matchers, &mut buf);
+            assert_eq!(buf, line(i, &matchers));
+        }
+
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_run_count() {
+        let matchers = Matcher::classic();
+
+        let mut out = Vec::new();
+        assert_eq!(fizzbuzz_run_to(&mut out, 1, 100, &matchers).unwrap(), 100);
+        assert_eq!(out.iter().filter(|b| **b == b'\n').count(), 100);
+
+        let mut out = Vec::new();
+        assert_eq!(fizzbuzz_run_to(&mut out, 7, 7, &matchers).unwrap(), 1);
+        assert_eq!(out, b"7\n");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_run_from_bigger_than_to() {
+        let mut out = Vec::new();
+
+        assert!(matches!(
+            fizzbuzz_run_to(&mut out, 2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+        assert!(out.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_line_byte_count() {
+        let matchers = Matcher::classic();
+        let mut out = Vec::new();
+        let mut total = 0;
+
+        for i in 1..=100 {
+            let before = out.len();
+            let written = write_line(i, &matchers, &mut out).unwrap();
+
+            assert_eq!(written, out.len() - before);
+            assert_eq!(written, line(i, &matchers).len() + 1);
+            total += written;
+        }
+
+        assert_eq!(total, out.len());
+
+        let mut expected = Vec::new();
+        fizzbuzz_to(&mut expected, 1, 100, &matchers).unwrap();
+        assert_eq!(out, expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_line_error() {
+        let result = write_line(3, &Matcher::classic(), &mut FailingWriter::new(1));
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn push_number_matches_to_string() {
+        let mut numbers = vec![0, 1, 9, 10, 99, 100, usize::MAX];
+        numbers.extend((0..1000).map(|_| random::<usize>()));
+
+        let mut out = String::new();
+        for i in numbers {
+            out.clear();
+            push_number(i, &mut out);
+            assert_eq!(out, i.to_string());
+        }
+    }
+
+    #[test]
+    fn fallback_string_matches_line() {
+        for i in (0..1000).chain(usize::MAX - 10..=usize::MAX) {
+            assert_eq!(fallback_string(i), i.to_string());
+            assert_eq!(fallback_string(i), line(i, &[]));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_each_order() {
+        let matchers = Matcher::classic();
+        let numbers = [16, 8, 15, 2, 4, 30, 2, 0, usize::MAX];
+
+        let mut out = Vec::new();
+        fizzbuzz_each_to(&mut out, numbers.iter().copied(), &matchers).unwrap();
+
+        let expected: String = numbers
+            .iter()
+            .map(|i| format!("{}\n", line(*i, &matchers)))
+            .collect();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_each_empty() {
+        let mut out = Vec::new();
+        fizzbuzz_each_to(&mut out, Vec::new(), &Matcher::classic()).unwrap();
+
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn line_empty_word() {
+        let matchers = vec![
+            Matcher::from_fn("", |n| n % 2 == 0),
+            Matcher::new(3, "Fizz").unwrap(),
+        ];
+
+        assert_eq!(line(1, &matchers), "1");
+        assert_eq!(line(2, &matchers), "");
+        assert_eq!(line(3, &matchers), "Fizz");
+        assert_eq!(line(6, &matchers), "Fizz");
+        assert_eq!(line_with(6, &matchers, "-"), "-Fizz");
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn line_no_std() {
+        let matchers = Matcher::classic();
+
+        let lines: Vec<_> = (1..=15).map(|i| line(i, &matchers)).collect();
+        assert_eq!(lines[2], "Fizz");
+        assert_eq!(lines[4], "Buzz");
+        assert_eq!(lines[14], "FizzBuzz");
+        assert_eq!(lines, fizzbuzz_lines(1, 15, &matchers).unwrap());
+    }
+
+    #[test]
+    fn line_fmt_fallback() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_fmt(7, &matchers, |n| format!("[{}]", n)), "[7]");
+        assert_eq!(line_fmt(9, &matchers, |n| format!("[{}]", n)), "Fizz");
+        assert_eq!(line_fmt(15, &matchers, |n| format!("[{}]", n)), "FizzBuzz");
+        assert_eq!(line_fmt(7, &matchers, |_| String::new()), "");
+
+        for i in 0..100 {
+            assert_eq!(
+                line_fmt(i, &matchers, |n| n.to_string()),
+                line(i, &matchers)
+            );
+        }
     }
 }
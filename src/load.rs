@@ -0,0 +1,209 @@
+use crate::matcher::serde_impl::Repr;
+use crate::{Matcher, MatcherError};
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The layout of a TOML config file.
+#[derive(Deserialize)]
+struct TomlFile {
+    matchers: Vec<Repr>,
+}
+
+/// Load matchers from a config file.
+///
+/// The format of the file is inferred from its extension:
+/// - `.json` files contain an array of matchers, like `[{"number":3,"word":"Fizz"}]`.
+/// - `.toml` files contain an array of tables named `matchers`, like this:
+///
+/// ```toml
+/// [[matchers]]
+/// number = 3
+/// word = "Fizz"
+/// ```
+///
+/// The matchers use the format of the `Serialize` implementation of `Matcher`.
+///
+/// # Errors
+/// - Returns `ConfigError::Io`, if reading the file fails, e.g. because it doesn't exist.
+/// - Returns `ConfigError::UnknownFormat`, if the extension is neither `.json` nor `.toml`.
+/// - Returns `ConfigError::Json` or `ConfigError::Toml`, if the file can't be parsed.
+/// - Returns `ConfigError::Matcher`, if one of the matchers is invalid, e.g. because its number
+///   is 0.
+///
+/// # Example
+/// ```no_run
+/// use extended_fizzbuzz::{fizzbuzz, load_matchers};
+/// use std::path::Path;
+///
+/// let matchers = load_matchers(Path::new("fizzbuzz.toml")).unwrap();
+/// fizzbuzz(1, 100, &matchers).unwrap();
+/// ```
+pub fn load_matchers(path: &Path) -> Result<Vec<Matcher>, ConfigError> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+
+    let reprs = match extension.as_deref() {
+        Some("json") => serde_json::from_str(&fs::read_to_string(path)?)?,
+        Some("toml") => toml::from_str::<TomlFile>(&fs::read_to_string(path)?)?.matchers,
+        _ => {
+            return Err(ConfigError::UnknownFormat {
+                path: path.to_owned(),
+            })
+        }
+    };
+
+    reprs
+        .into_iter()
+        .enumerate()
+        .map(|(index, repr): (usize, Repr)| {
+            repr.into_matcher()
+                .map_err(|source| ConfigError::Matcher { index, source })
+        })
+        .collect()
+}
+
+/// All errors the `load_matchers()` function can produce.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// Reading the config file failed.
+    #[error("reading the config file failed: {0}")]
+    Io(#[from] io::Error),
+
+    /// The format of the config file can't be inferred from its extension.
+    #[error("unknown config file format: `{}`", path.display())]
+    UnknownFormat { path: PathBuf },
+
+    /// The config file isn't valid JSON, or doesn't have the expected layout.
+    #[error("invalid JSON config file: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The config file isn't valid TOML, or doesn't have the expected layout.
+    #[error("invalid TOML config file: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    /// A matcher in the config file is invalid. Contains the position of the matcher in the file,
+    /// starting at 0.
+    #[error("matcher {index} is invalid: {source}")]
+    Matcher { index: usize, source: MatcherError },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    /// A fixture file in the temp dir, that is removed when dropped.
+    struct Fixture(PathBuf);
+
+    impl Fixture {
+        fn new(name: &str, content: &str) -> Self {
+            let path =
+                env::temp_dir().join(format!("extended_fizzbuzz-{}-{}", process::id(), name));
+            fs::write(&path, content).unwrap();
+
+            Fixture(path)
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn load_json() {
+        let file = Fixture::new(
+            "load_json.json",
+            r#"[{"number":3,"word":"Fizz"},{"number":5,"word":"Buzz"}]"#,
+        );
+
+        assert_eq!(load_matchers(&file.0).unwrap(), Matcher::classic());
+    }
+
+    #[test]
+    fn load_toml() {
+        let file = Fixture::new(
+            "load_toml.toml",
+            r#"
+            [[matchers]]
+            number = 3
+            word = "Fizz"
+
+            [[matchers]]
+            number = 5
+            word = "Buzz"
+
+            [[matchers]]
+            kind = "exactly"
+            number = 42
+            word = "Answer"
+            "#,
+        );
+
+        let mut expected = Matcher::classic();
+        expected.push(Matcher::exactly(42, "Answer"));
+
+        assert_eq!(load_matchers(&file.0).unwrap(), expected);
+    }
+
+    #[test]
+    fn load_not_found() {
+        let path = env::temp_dir().join("extended_fizzbuzz-does-not-exist.json");
+
+        match load_matchers(&path) {
+            Err(ConfigError::Io(e)) => assert_eq!(e.kind(), io::ErrorKind::NotFound),
+            res => panic!("expected an io error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn load_unknown_format() {
+        let file = Fixture::new("load_unknown_format.yaml", "- number: 3");
+
+        assert!(matches!(
+            load_matchers(&file.0),
+            Err(ConfigError::UnknownFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn load_parse_error() {
+        let file = Fixture::new("load_parse_error.json", "[{");
+        assert!(matches!(load_matchers(&file.0), Err(ConfigError::Json(_))));
+
+        let file = Fixture::new("load_parse_error.toml", "matchers = 3");
+        assert!(matches!(load_matchers(&file.0), Err(ConfigError::Toml(_))));
+    }
+
+    #[test]
+    fn load_invalid_matcher() {
+        let file = Fixture::new(
+            "load_invalid_matcher.json",
+            r#"[{"number":3,"word":"Fizz"},{"number":0,"word":"Zero"}]"#,
+        );
+        assert!(matches!(
+            load_matchers(&file.0),
+            Err(ConfigError::Matcher {
+                index: 1,
+                source: MatcherError::NumberIsZero
+            })
+        ));
+
+        let file = Fixture::new("load_invalid_matcher.toml", "[[matchers]]\nword = \"Fizz\"");
+        assert!(matches!(
+            load_matchers(&file.0),
+            Err(ConfigError::Matcher {
+                index: 0,
+                source: MatcherError::MissingField { field: "number" }
+            })
+        ));
+    }
+}
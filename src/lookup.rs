@@ -0,0 +1,257 @@
+use crate::Matcher;
+use alloc::vec::Vec;
+
+/// Check wether any matcher substitutes numbers with `word`.
+///
+/// Words are compared exactly, so the check is case sensitive.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{has_word, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert!(has_word(&matchers, "Fizz"));
+/// assert!(!has_word(&matchers, "fizz"));
+/// assert!(!has_word(&matchers, "Bazz"));
+/// ```
+pub fn has_word(matchers: &[Matcher], word: &str) -> bool {
+    matchers.iter().any(|m| m.word() == word)
+}
+
+/// Find the first matcher created by `Matcher::new()` with a specific divisor.
+///
+/// Returns `None`, if no such matcher exists. Matchers of other kinds are ignored, even if they
+/// have the same `number()`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{find_by_divisor, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(find_by_divisor(&matchers, 5).map(|m| m.word()), Some("Buzz"));
+/// assert_eq!(find_by_divisor(&matchers, 7), None);
+/// ```
+pub fn find_by_divisor(matchers: &[Matcher], number: usize) -> Option<&Matcher> {
+    matchers.iter().find(|m| m.divisor() == Some(number))
+}
+
+/// Find the positions of all matchers matching `number`.
+///
+/// The positions are returned in ascending order. If no matcher matches, the result is empty.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{matching_indices, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(matching_indices(15, &matchers), vec![0, 1]);
+/// assert_eq!(matching_indices(10, &matchers), vec![1]);
+/// assert!(matching_indices(7, &matchers).is_empty());
+/// ```
+pub fn matching_indices(number: usize, matchers: &[Matcher]) -> Vec<usize> {
+    matchers
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| m.matches(number))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Find the positions of all matchers matching `number`, as a bitmask.
+///
+/// Bit `i` of the result is set, if the matcher at position `i` matches. This works the same way
+/// as `matching_indices()`, but doesn't allocate.
+///
+/// Only the first 64 matchers are checked. All following matchers are ignored, so they never show
+/// up in the result.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{matches_mask, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(matches_mask(15, &matchers), 0b11);
+/// assert_eq!(matches_mask(10, &matchers), 0b10);
+/// assert_eq!(matches_mask(7, &matchers), 0);
+/// ```
+pub fn matches_mask(number: usize, matchers: &[Matcher]) -> u64 {
+    matchers
+        .iter()
+        .take(64)
+        .enumerate()
+        .filter(|(_, m)| m.matches(number))
+        .fold(0, |mask, (i, _)| mask | 1 << i)
+}
+
+/// Check wether at least one matcher matches `number`.
+///
+/// Returns `false` for an empty slice of matchers.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{matches_any, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert!(matches_any(6, &matchers));
+/// assert!(!matches_any(7, &matchers));
+/// ```
+pub fn matches_any(number: usize, matchers: &[Matcher]) -> bool {
+    matchers.iter().any(|m| m.matches(number))
+}
+
+/// Check wether every matcher matches `number`.
+///
+/// Returns `true` for an empty slice of matchers.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{matches_all, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert!(matches_all(15, &matchers));
+/// assert!(!matches_all(6, &matchers));
+/// ```
+pub fn matches_all(number: usize, matchers: &[Matcher]) -> bool {
+    matchers.iter().all(|m| m.matches(number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matchers() -> Vec<Matcher> {
+        vec![
+            Matcher::exactly(3, "Three"),
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+            Matcher::new(5, "Bizz").unwrap(),
+            Matcher::with_remainder(7, 1, "Bazz").unwrap(),
+        ]
+    }
+
+    #[test]
+    fn has_word_present() {
+        let matchers = matchers();
+
+        for word in ["Three", "Fizz", "Buzz", "Bizz", "Bazz"].iter() {
+            assert!(has_word(&matchers, word));
+        }
+    }
+
+    #[test]
+    fn has_word_absent() {
+        let matchers = matchers();
+
+        assert!(!has_word(&matchers, "FizzBuzz"));
+        assert!(!has_word(&matchers, "fizz"));
+        assert!(!has_word(&matchers, ""));
+        assert!(!has_word(&[], "Fizz"));
+    }
+
+    #[test]
+    fn find_by_divisor_present() {
+        let matchers = matchers();
+
+        assert_eq!(find_by_divisor(&matchers, 3), Some(&matchers[1]));
+        assert_eq!(find_by_divisor(&matchers, 5), Some(&matchers[2]));
+    }
+
+    #[test]
+    fn find_by_divisor_absent() {
+        let matchers = matchers();
+
+        assert_eq!(find_by_divisor(&matchers, 7), None);
+        assert_eq!(find_by_divisor(&matchers, 15), None);
+        assert_eq!(find_by_divisor(&[], 3), None);
+    }
+
+    #[test]
+    fn matching_indices_classic() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(matching_indices(15, &matchers), vec![0, 1]);
+        assert_eq!(matching_indices(3, &matchers), vec![0]);
+        assert_eq!(matching_indices(7, &matchers), Vec::<usize>::new());
+        assert!(matching_indices(15, &[]).is_empty());
+    }
+
+    #[test]
+    fn matching_indices_mixed() {
+        let matchers = matchers();
+
+        assert_eq!(matching_indices(3, &matchers), vec![0, 1]);
+        assert_eq!(matching_indices(15, &matchers), vec![1, 2, 3, 4]);
+        assert_eq!(matching_indices(8, &matchers), vec![4]);
+    }
+
+    #[test]
+    fn matches_mask_classic() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(matches_mask(15, &matchers), 0b11);
+        assert_eq!(matches_mask(3, &matchers), 0b01);
+        assert_eq!(matches_mask(5, &matchers), 0b10);
+        assert_eq!(matches_mask(7, &matchers), 0);
+        assert_eq!(matches_mask(15, &[]), 0);
+    }
+
+    #[test]
+    fn matches_mask_indices() {
+        let matchers = matchers();
+
+        for i in 0..100 {
+            let mask = matching_indices(i, &matchers)
+                .into_iter()
+                .fold(0, |mask, i| mask | 1 << i);
+            assert_eq!(matches_mask(i, &matchers), mask);
+        }
+    }
+
+    #[test]
+    fn matches_mask_truncated() {
+        let matchers = vec![Matcher::new(1, "X").unwrap(); 70];
+
+        assert_eq!(matches_mask(1, &matchers[..64]), u64::MAX);
+        assert_eq!(matches_mask(1, &matchers), u64::MAX);
+        assert_eq!(matches_mask(1, &matchers[..3]), 0b111);
+    }
+
+    #[test]
+    fn matches_any_classic() {
+        let matchers = Matcher::classic();
+
+        assert!(matches_any(6, &matchers));
+        assert!(matches_any(10, &matchers));
+        assert!(matches_any(15, &matchers));
+        assert!(!matches_any(7, &matchers));
+        assert!(!matches_any(15, &[]));
+    }
+
+    #[test]
+    fn matches_all_classic() {
+        let matchers = Matcher::classic();
+
+        assert!(matches_all(15, &matchers));
+        assert!(matches_all(0, &matchers));
+        assert!(!matches_all(6, &matchers));
+        assert!(!matches_all(7, &matchers));
+        assert!(matches_all(7, &[]));
+    }
+
+    #[test]
+    fn matches_any_all_filter() {
+        let matchers = Matcher::classic();
+
+        let any: Vec<_> = (1..=15).filter(|n| matches_any(*n, &matchers)).collect();
+        let all: Vec<_> = (1..=30).filter(|n| matches_all(*n, &matchers)).collect();
+
+        assert_eq!(any, vec![3, 5, 6, 9, 10, 12, 15]);
+        assert_eq!(all, vec![15, 30]);
+    }
+}
@@ -0,0 +1,68 @@
+/// Create a vector of `Matcher`s.
+///
+/// Every entry has the form `number => word` and is passed to `Matcher::new()`. The result is a
+/// `Result<Vec<Matcher>, MatcherError>`, so invalid entries (like a `number` of 0, that is only
+/// known at runtime) can be handled without panicking.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line, matchers};
+///
+/// let matchers = matchers![3 => "Fizz", 5 => "Buzz"].unwrap();
+/// assert_eq!(line(15, &matchers), "FizzBuzz");
+///
+/// let divisor = 0;
+/// assert!(matchers![divisor => "Zero"].is_err());
+/// ```
+#[macro_export]
+macro_rules! matchers {
+    ($($number:expr => $word:expr),* $(,)?) => {
+        $crate::__alloc::vec![$($crate::Matcher::new($number, $word)),*]
+            .into_iter()
+            .collect::<::core::result::Result<
+                $crate::__alloc::vec::Vec<$crate::Matcher>,
+                $crate::MatcherError,
+            >>()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matcher, MatcherError};
+
+    #[test]
+    fn matchers_normal() {
+        let matchers = matchers![3 => "Fizz", 5 => "Buzz"].unwrap();
+
+        assert_eq!(
+            matchers,
+            vec![
+                Matcher::new(3, "Fizz").unwrap(),
+                Matcher::new(5, "Buzz").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn matchers_trailing_comma() {
+        let matchers = matchers![
+            3 => "Fizz",
+            5 => "Buzz",
+        ]
+        .unwrap();
+
+        assert_eq!(matchers, Matcher::classic());
+    }
+
+    #[test]
+    fn matchers_empty() {
+        let matchers = matchers![].unwrap();
+        assert!(matchers.is_empty());
+    }
+
+    #[test]
+    fn matchers_zero() {
+        let res = matchers![3 => "Fizz", 0 => "Zero", 5 => "Buzz"];
+        assert!(matches!(res, Err(MatcherError::NumberIsZero)));
+    }
+}
@@ -1,3 +1,18 @@
+#[cfg(feature = "color")]
+use crate::Color;
+use crate::{Bounded, FizzBuzzError};
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::num::ParseIntError;
+use core::str::FromStr;
 use thiserror::Error;
 
 /// A container for configuration values.
@@ -12,13 +27,166 @@ use thiserror::Error;
 /// let buzz_message = String::from("Buzz");
 /// let buzz = Matcher::new(5, &buzz_message).unwrap();
 /// ```
-#[derive(Debug)]
+///
+/// # Words
+/// `Matcher::new()` and the other constructors listing `MatcherError::EmptyWord` in their errors
+/// reject empty words. The remaining ones, like `Matcher::exactly()` or `Matcher::from_fn()`,
+/// accept them. Numbers only matched by such a matcher are substituted by an empty line.
+///
+/// # Equality
+/// Matchers created by `Matcher::from_fn()` are only equal to their clones, as closures can't be
+/// compared. Two matchers created from identical closures are never equal.
+///
+/// # Ordering
+/// Matchers are ordered by their `number()` first, and by their word second. Matchers without a
+/// number come first. This means sorting a vector of matchers created by `Matcher::new()` sorts
+/// them by their divisor:
+/// ```
+/// # use extended_fizzbuzz::{line, Matcher};
+/// let mut matchers = vec![
+///     Matcher::new(5, "Buzz").unwrap(),
+///     Matcher::new(3, "Fizz").unwrap(),
+/// ];
+/// matchers.sort();
+///
+/// assert_eq!(matchers, Matcher::classic());
+/// assert_eq!(line(15, &matchers), "FizzBuzz");
+/// ```
+///
+/// Matchers with the same number and word, but of different kinds, are ordered in an unspecified,
+/// but consistent way.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Matcher {
-    number: usize,
-    word: String,
+    word: Cow<'static, str>,
+    kind: Kind,
+    #[cfg(feature = "color")]
+    color: Option<Color>,
+}
+
+/// The rules a `Matcher` can use to decide wether a number should be substituted.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Kind {
+    /// The number divided by `number` leaves `remainder`.
+    ///
+    /// If `number` is a power of two, `bitmask` is `number - 1`, so that the remainder can be
+    /// calculated with a bitwise and. It only depends on `number`, so it doesn't change how
+    /// matchers compare.
+    Remainder {
+        number: usize,
+        remainder: usize,
+        bitmask: Option<usize>,
+    },
+    /// The number is equal to the contained number.
+    Exactly(usize),
+    /// The number lies between `low` and `high`, both inclusive.
+    Range { low: usize, high: usize },
+    /// The contained predicate returns `true` for the number.
+    Predicate(Predicate),
+    /// The number is a prime number.
+    Prime,
+    /// The number is a perfect square.
+    PerfectSquare,
+    /// The decimal representation of the number contains the contained digit.
+    ContainsDigit(u8),
+    /// The number is divisible by all of the contained divisors.
+    AllOf(Vec<usize>),
+    /// The number is divisible by at least one of the contained divisors.
+    AnyOf(Vec<usize>),
+    /// The number is not divisible by the contained number.
+    NotDivisible(usize),
+    /// The sum of the decimal digits of the number is divisible by the contained number.
+    DigitSumDivisible(usize),
+    /// The decimal representation of the number reads the same forwards and backwards.
+    Palindrome,
+    /// The number is a triangular number.
+    Triangular,
+    /// The number is a power of the contained base.
+    PowerOf(usize),
+    /// Both contained matchers match the number.
+    And(Box<Matcher>, Box<Matcher>),
+    /// At least one of the contained matchers matches the number.
+    Or(Box<Matcher>, Box<Matcher>),
+}
+
+/// A shared closure deciding wether a number should be substituted.
+///
+/// Closures can't be compared, so predicates are only equal to their clones.
+#[derive(Clone)]
+struct Predicate(Arc<dyn Fn(usize) -> bool + Send + Sync>);
+
+impl Predicate {
+    fn address(&self) -> *const () {
+        Arc::as_ptr(&self.0) as *const ()
+    }
+}
+
+impl Debug for Predicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Predicate({:p})", self.address())
+    }
+}
+
+impl PartialEq for Predicate {
+    fn eq(&self, other: &Self) -> bool {
+        self.address() == other.address()
+    }
+}
+
+impl Eq for Predicate {}
+
+impl Hash for Predicate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+    }
+}
+
+impl PartialOrd for Predicate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Predicate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.address().cmp(&other.address())
+    }
+}
+
+impl PartialOrd for Matcher {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Matcher {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = self
+            .number()
+            .cmp(&other.number())
+            .then_with(|| self.word.cmp(&other.word))
+            .then_with(|| self.kind.cmp(&other.kind));
+
+        #[cfg(feature = "color")]
+        let ordering = ordering.then_with(|| self.color.cmp(&other.color));
+
+        ordering
+    }
 }
 
 impl Matcher {
+    fn with_kind(kind: Kind, word: &str) -> Self {
+        Matcher::with_cow(kind, Cow::Owned(word.to_owned()))
+    }
+
+    fn with_cow(kind: Kind, word: Cow<'static, str>) -> Self {
+        Matcher {
+            word,
+            kind,
+            #[cfg(feature = "color")]
+            color: None,
+        }
+    }
+
     /// Create a new matcher.
     ///
     /// # Parameters
@@ -26,154 +194,2860 @@ impl Matcher {
     /// configured text. For this, the checked number is divided by the `number` parameters value,
     /// which therefore mustn't be 0.
     ///
+    /// The `word` parameter is the text numbers are substituted by. It mustn't be empty, but may
+    /// consist only of whitespace.
+    ///
     /// # Errors
     /// - Returns `MatcherError::NumberIsZero` if the `number` parameter is 0.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
     pub fn new(number: usize, word: &str) -> Result<Self, MatcherError> {
-        if number == 0 {
-            return Err(MatcherError::NumberIsZero);
-        }
-
-        Ok(Matcher {
-            number: number,
-            word: word.to_owned(),
-        })
+        Matcher::with_remainder(number, 0, word)
     }
 
-    /// Check wether the `number` should be substituted.
+    /// Create a new matcher, without copying its word.
+    ///
+    /// Works the same way as `Matcher::new()`, but borrows the `word` instead of allocating a copy
+    /// of it. This is useful for string literals.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NumberIsZero` if the `number` parameter is 0.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
     ///
     /// # Example
     /// ```
     /// # use extended_fizzbuzz::Matcher;
-    /// let number = 3;
-    /// let matcher = Matcher::new(number, "Fizz").unwrap();
+    /// let fizz = Matcher::new_static(3, "Fizz").unwrap();
     ///
-    /// assert_eq!(matcher.matches(number - 1), false);
-    /// assert_eq!(matcher.matches(number), true);
-    /// assert_eq!(matcher.matches(number + 1), false);
+    /// assert_eq!(fizz, Matcher::new(3, "Fizz").unwrap());
     /// ```
-    pub fn matches(self: &Self, number: usize) -> bool {
-        number % self.number == 0
+    pub fn new_static(number: usize, word: &'static str) -> Result<Self, MatcherError> {
+        Matcher::remainder_cow(number, 0, Cow::Borrowed(word))
     }
 
-    /// Get the text the `number` should be substituted by.
+    /// Create a new matcher, taking ownership of its word.
     ///
-    /// If the `number` should be substituted, returns the appropriate text. Otherwise returns an
-    /// empty string.
+    /// Works the same way as `Matcher::new()`, but uses the `word` directly instead of allocating a
+    /// copy of it. This is useful for words created at runtime.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NumberIsZero` if the `number` parameter is 0.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
     ///
     /// # Example
     /// ```
     /// # use extended_fizzbuzz::Matcher;
-    /// let text = "Fizz";
-    /// let number = 3;
-    /// let matcher = Matcher::new(number, text).unwrap();
+    /// let word = format!("{}{}", "Fi", "zz");
+    /// let fizz = Matcher::new_owned(3, word).unwrap();
     ///
-    /// assert_eq!(matcher.text(number - 1), "");
-    /// assert_eq!(matcher.text(number), text);
-    /// assert_eq!(matcher.text(number + 1), "");
+    /// assert_eq!(fizz, Matcher::new(3, "Fizz").unwrap());
     /// ```
-    pub fn text(self: &Self, number: usize) -> &str {
-        if self.matches(number) {
-            return &self.word;
-        }
-
-        ""
+    pub fn new_owned(number: usize, word: String) -> Result<Self, MatcherError> {
+        Matcher::remainder_cow(number, 0, Cow::Owned(word))
     }
-}
-
-/// All errors a `Matcher` can produce.
-#[derive(Error, Debug)]
-#[non_exhaustive]
-pub enum MatcherError {
-    /// The `number` parameter of a `Matcher` was set to 0. This would lead to divisions by zero,
-    /// which is mathematically impossible.
-    #[error("`number` is 0, but division by 0 is impossible")]
-    NumberIsZero,
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rand::random;
+    /// Create a new matcher, that substitutes numbers leaving a specific remainder.
+    ///
+    /// # Parameters
+    /// The `number` and `word` parameters work the same way as for `Matcher::new()`.
+    ///
+    /// The `remainder` parameter is the remainder a number has to leave when divided by `number`
+    /// to be substituted. It has to be smaller than `number`, otherwise no number would ever be
+    /// substituted. `Matcher::new()` is the same as using a `remainder` of 0.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NumberIsZero` if the `number` parameter is 0.
+    /// - Returns `MatcherError::RemainderTooLarge` if the `remainder` parameter isn't smaller than
+    ///   the `number` parameter.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matcher = Matcher::with_remainder(3, 1, "Off").unwrap();
+    ///
+    /// assert_eq!(matcher.matches(3), false);
+    /// assert_eq!(matcher.matches(4), true);
+    /// assert_eq!(matcher.matches(5), false);
+    ///
+    /// assert_eq!(Matcher::with_remainder(3, 0, "Fizz").unwrap(), Matcher::new(3, "Fizz").unwrap());
+    /// assert!(Matcher::with_remainder(3, 3, "Never").is_err());
+    /// ```
+    pub fn with_remainder(
+        number: usize,
+        remainder: usize,
+        word: &str,
+    ) -> Result<Self, MatcherError> {
+        Matcher::remainder_cow(number, remainder, Cow::Owned(word.to_owned()))
+    }
 
-    #[test]
-    fn new_normal() {
-        let word = "Test";
-        let mut number = 0;
-        while number == 0 {
-            number = random();
+    fn remainder_cow(
+        number: usize,
+        remainder: usize,
+        word: Cow<'static, str>,
+    ) -> Result<Self, MatcherError> {
+        if number == 0 {
+            return Err(MatcherError::NumberIsZero);
+        }
+        if remainder >= number {
+            return Err(MatcherError::RemainderTooLarge { number, remainder });
+        }
+        if word.is_empty() {
+            return Err(MatcherError::EmptyWord);
         }
 
-        let matcher = Matcher::new(number, word).unwrap();
-        assert_eq!(matcher.number, number);
-        assert_eq!(matcher.word, word.to_string());
-    }
-
-    #[test]
-    fn new_zero() -> Result<(), String> {
-        let word = "Test";
-        let number = 0;
+        let bitmask = if number.is_power_of_two() {
+            Some(number - 1)
+        } else {
+            None
+        };
 
-        let matcher_res = Matcher::new(number, word);
-        match matcher_res {
-            Err(e) => match e {
-                MatcherError::NumberIsZero => Ok(()),
-                _ => Err("Wrong error kind".to_string()),
+        Ok(Matcher::with_cow(
+            Kind::Remainder {
+                number,
+                remainder,
+                bitmask,
             },
-            _ => Err("Wrongfully succeeded".to_string()),
-        }
+            word,
+        ))
     }
 
-    #[test]
-    fn matches_normal() {
-        let word = "Test";
-        let mut number = 0;
-        while number == 0 || number == 1 {
-            number = random();
+    /// Create a new matcher, that only substitutes a single number.
+    ///
+    /// # Parameters
+    /// The `number` parameter is the only number that is substituted by the configured text. Other
+    /// than for `Matcher::new()`, it may be 0.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![
+    ///     Matcher::new(3, "Fizz").unwrap(),
+    ///     Matcher::exactly(42, "Answer"),
+    /// ];
+    ///
+    /// assert_eq!(line(41, &matchers), "41");
+    /// assert_eq!(line(42, &matchers), "FizzAnswer");
+    /// assert_eq!(line(84, &matchers), "Fizz");
+    /// ```
+    pub fn exactly(number: usize, word: &str) -> Self {
+        Matcher::with_kind(Kind::Exactly(number), word)
+    }
+
+    /// Create a new matcher, that substitutes all numbers in a range.
+    ///
+    /// # Parameters
+    /// The `low` and `high` parameters define the range of numbers that are substituted by the
+    /// configured text. Both of them are inclusive.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::LowBiggerThanHigh` if the `low` parameters value is bigger than
+    ///   the `high` parameters value.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![
+    ///     Matcher::in_range(50, 59, "Fifties").unwrap(),
+    ///     Matcher::new(3, "Fizz").unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(line(49, &matchers), "49");
+    /// assert_eq!(line(50, &matchers), "Fifties");
+    /// assert_eq!(line(51, &matchers), "FiftiesFizz");
+    /// assert_eq!(line(60, &matchers), "Fizz");
+    /// ```
+    pub fn in_range(low: usize, high: usize, word: &str) -> Result<Self, MatcherError> {
+        if low > high {
+            return Err(MatcherError::LowBiggerThanHigh { low, high });
         }
 
-        let matcher = Matcher::new(number, word).unwrap();
+        Ok(Matcher::with_kind(Kind::Range { low, high }, word))
+    }
 
-        assert!(!matcher.matches(number - 1));
-        assert!(matcher.matches(number));
-        assert!(!matcher.matches(number + 1));
+    /// Create a new matcher, that uses an arbitrary rule.
+    ///
+    /// # Parameters
+    /// The `pred` parameter is called with every number that is checked. If it returns `true`, the
+    /// number is substituted by the configured text. It has to be `Send` and `Sync`, so that the
+    /// matcher can be shared between threads.
+    ///
+    /// Keep in mind that a matcher created by this function is only equal to its clones. See the
+    /// documentation of `Matcher` for details.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![
+    ///     Matcher::from_fn("Even", |n| n % 2 == 0),
+    ///     Matcher::new(3, "Fizz").unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(line(1, &matchers), "1");
+    /// assert_eq!(line(2, &matchers), "Even");
+    /// assert_eq!(line(6, &matchers), "EvenFizz");
+    /// ```
+    pub fn from_fn(word: &str, pred: impl Fn(usize) -> bool + Send + Sync + 'static) -> Self {
+        Matcher::with_kind(Kind::Predicate(Predicate(Arc::new(pred))), word)
     }
 
-    #[test]
-    fn matches_one() {
-        let word = "Test";
-        let number = 1;
+    /// Create a new matcher, that substitutes prime numbers.
+    ///
+    /// 0 and 1 are not prime numbers. Primality is checked by trial division, so checking a single
+    /// number `n` takes O(√n) steps.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::prime("Prime")];
+    ///
+    /// assert_eq!(line(1, &matchers), "1");
+    /// assert_eq!(line(2, &matchers), "Prime");
+    /// assert_eq!(line(9, &matchers), "9");
+    /// assert_eq!(line(11, &matchers), "Prime");
+    /// ```
+    pub fn prime(word: &str) -> Self {
+        Matcher::with_kind(Kind::Prime, word)
+    }
 
-        let matcher = Matcher::new(number, word).unwrap();
+    /// Create a new matcher, that substitutes perfect squares.
+    ///
+    /// 0 is a perfect square (0 * 0), so it is substituted as well. The check uses an integer
+    /// square root, so it is exact for all numbers.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::perfect_square("Square")];
+    ///
+    /// assert_eq!(line(0, &matchers), "Square");
+    /// assert_eq!(line(8, &matchers), "8");
+    /// assert_eq!(line(9, &matchers), "Square");
+    /// assert_eq!(line(10, &matchers), "10");
+    /// ```
+    pub fn perfect_square(word: &str) -> Self {
+        Matcher::with_kind(Kind::PerfectSquare, word)
+    }
 
-        assert!(matcher.matches(number - 1));
-        assert!(matcher.matches(number));
-        assert!(matcher.matches(number + 1));
+    /// Create a new matcher, that substitutes palindromic numbers.
+    ///
+    /// A number is a palindrome, if its decimal representation reads the same forwards and
+    /// backwards. Every number with a single digit is a palindrome.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::palindrome("Palindrome")];
+    ///
+    /// assert_eq!(line(7, &matchers), "Palindrome");
+    /// assert_eq!(line(10, &matchers), "10");
+    /// assert_eq!(line(121, &matchers), "Palindrome");
+    /// ```
+    pub fn palindrome(word: &str) -> Self {
+        Matcher::with_kind(Kind::Palindrome, word)
     }
 
-    #[test]
-    fn text_normal() {
-        let word = "Test";
-        let mut number = 0;
-        while number == 0 || number == 1 {
-            number = random();
+    /// Create a new matcher, that substitutes triangular numbers.
+    ///
+    /// A number `n` is triangular, if `n = k * (k + 1) / 2` for some positive integer `k`, like 1,
+    /// 3, 6, 10 or 15. 0 is not substituted. The check inverts the formula with an integer square
+    /// root, so it takes constant time and is exact for all numbers.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::triangular("Triangle")];
+    ///
+    /// assert_eq!(line(0, &matchers), "0");
+    /// assert_eq!(line(10, &matchers), "Triangle");
+    /// assert_eq!(line(11, &matchers), "11");
+    /// ```
+    pub fn triangular(word: &str) -> Self {
+        Matcher::with_kind(Kind::Triangular, word)
+    }
+
+    /// Create a new matcher, that substitutes powers of a specific base.
+    ///
+    /// A number is a power of `base`, if it is `base` multiplied with itself any number of times.
+    /// As `base` to the power of 0 is 1, 1 is a power of every base.
+    ///
+    /// # Parameters
+    /// The `base` parameter is the number a number has to be a power of to be substituted. It has
+    /// to be at least 2, because the only powers of 0 and 1 are 0 and 1 themselves.
+    ///
+    /// The `word` parameter works the same way as for `Matcher::new()`.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::BaseTooSmall` if the `base` parameter is 0 or 1.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::power_of(2, "Power").unwrap()];
+    ///
+    /// assert_eq!(line(1, &matchers), "Power");
+    /// assert_eq!(line(6, &matchers), "6");
+    /// assert_eq!(line(16, &matchers), "Power");
+    /// ```
+    pub fn power_of(base: usize, word: &str) -> Result<Self, MatcherError> {
+        if base < 2 {
+            return Err(MatcherError::BaseTooSmall { base });
+        }
+        if word.is_empty() {
+            return Err(MatcherError::EmptyWord);
         }
 
-        let matcher = Matcher::new(number, word).unwrap();
+        Ok(Matcher::with_kind(Kind::PowerOf(base), word))
+    }
 
-        assert_eq!(matcher.text(number - 1), "");
-        assert_eq!(matcher.text(number), word);
-        assert_eq!(matcher.text(number + 1), "");
+    /// Create a new matcher, that substitutes numbers containing a specific digit.
+    ///
+    /// # Parameters
+    /// The `digit` parameter is the digit the decimal representation of a number has to contain
+    /// to be substituted. It has to be between 0 and 9.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::DigitTooLarge` if the `digit` parameter is bigger than 9.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![
+    ///     Matcher::new(3, "Fizz").unwrap(),
+    ///     Matcher::contains_digit(3, "Fizz").unwrap(),
+    /// ];
+    ///
+    /// assert_eq!(line(3, &matchers), "FizzFizz");
+    /// assert_eq!(line(6, &matchers), "Fizz");
+    /// assert_eq!(line(13, &matchers), "Fizz");
+    /// assert_eq!(line(14, &matchers), "14");
+    /// ```
+    pub fn contains_digit(digit: u8, word: &str) -> Result<Self, MatcherError> {
+        if digit > 9 {
+            return Err(MatcherError::DigitTooLarge { digit });
+        }
+
+        Ok(Matcher::with_kind(Kind::ContainsDigit(digit), word))
     }
 
-    #[test]
-    fn text_one() {
-        let word = "Test";
-        let number = 1;
+    /// Create a new matcher, that substitutes numbers divisible by all of multiple divisors.
+    ///
+    /// Other than using a matcher per divisor, the `word` is only used once, and only if every
+    /// divisor divides the number.
+    ///
+    /// # Parameters
+    /// The `divisors` parameter contains the numbers a number has to be divisible by to be
+    /// substituted. It mustn't be empty, and none of the divisors may be 0.
+    ///
+    /// The `word` parameter works the same way as for `Matcher::new()`.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NoDivisors` if the `divisors` parameter is empty.
+    /// - Returns `MatcherError::NumberIsZero` if any of the `divisors` is 0.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::all_of(&[3, 4], "Twelve").unwrap()];
+    ///
+    /// assert_eq!(line(6, &matchers), "6");
+    /// assert_eq!(line(12, &matchers), "Twelve");
+    /// assert_eq!(line(24, &matchers), "Twelve");
+    /// ```
+    pub fn all_of(divisors: &[usize], word: &str) -> Result<Self, MatcherError> {
+        check_divisors(divisors, word)?;
 
-        let matcher = Matcher::new(number, word).unwrap();
+        Ok(Matcher::with_kind(Kind::AllOf(divisors.to_vec()), word))
+    }
 
-        assert_eq!(matcher.text(number - 1), word);
-        assert_eq!(matcher.text(number), word);
-        assert_eq!(matcher.text(number + 1), word);
+    /// Create a new matcher, that substitutes numbers divisible by any of multiple divisors.
+    ///
+    /// Other than using a matcher per divisor, the `word` is only used once, even if multiple
+    /// divisors divide the number.
+    ///
+    /// # Parameters
+    /// The `divisors` parameter contains the numbers a number can be divisible by to be
+    /// substituted. It mustn't be empty, and none of the divisors may be 0.
+    ///
+    /// The `word` parameter works the same way as for `Matcher::new()`.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NoDivisors` if the `divisors` parameter is empty.
+    /// - Returns `MatcherError::NumberIsZero` if any of the `divisors` is 0.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::any_of(&[3, 7], "Flag").unwrap()];
+    ///
+    /// assert_eq!(line(3, &matchers), "Flag");
+    /// assert_eq!(line(5, &matchers), "5");
+    /// assert_eq!(line(21, &matchers), "Flag");
+    /// ```
+    pub fn any_of(divisors: &[usize], word: &str) -> Result<Self, MatcherError> {
+        check_divisors(divisors, word)?;
+
+        Ok(Matcher::with_kind(Kind::AnyOf(divisors.to_vec()), word))
+    }
+
+    /// Create a new matcher, that substitutes numbers not divisible by a specific number.
+    ///
+    /// This is the inverse of `Matcher::new()`. As every number is divisible by 1, a negated
+    /// matcher using 1 never matches.
+    ///
+    /// # Parameters
+    /// The `number` parameter is the number a number mustn't be divisible by to be substituted. It
+    /// mustn't be 0.
+    ///
+    /// The `word` parameter works the same way as for `Matcher::new()`.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NumberIsZero` if the `number` parameter is 0.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::negated(5, "NotBuzz").unwrap()];
+    ///
+    /// assert_eq!(line(4, &matchers), "NotBuzz");
+    /// assert_eq!(line(5, &matchers), "5");
+    /// ```
+    pub fn negated(number: usize, word: &str) -> Result<Self, MatcherError> {
+        if number == 0 {
+            return Err(MatcherError::NumberIsZero);
+        }
+        if word.is_empty() {
+            return Err(MatcherError::EmptyWord);
+        }
+
+        Ok(Matcher::with_kind(Kind::NotDivisible(number), word))
+    }
+
+    /// Create a new matcher, that substitutes numbers whose digit sum is divisible by a specific
+    /// number.
+    ///
+    /// The digit sum is the sum of the digits of the decimal representation of a number. A number
+    /// is divisible by 3 or 9 exactly if its digit sum is, but that doesn't hold for other
+    /// divisors.
+    ///
+    /// # Parameters
+    /// The `by` parameter is the number the digit sum has to be divisible by for a number to be
+    /// substituted. It mustn't be 0.
+    ///
+    /// The `word` parameter works the same way as for `Matcher::new()`.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NumberIsZero` if the `by` parameter is 0.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::digit_sum_divisible(5, "Five").unwrap()];
+    ///
+    /// assert_eq!(line(14, &matchers), "Five");
+    /// assert_eq!(line(15, &matchers), "15");
+    /// assert_eq!(line(23, &matchers), "Five");
+    /// ```
+    pub fn digit_sum_divisible(by: usize, word: &str) -> Result<Self, MatcherError> {
+        if by == 0 {
+            return Err(MatcherError::NumberIsZero);
+        }
+        if word.is_empty() {
+            return Err(MatcherError::EmptyWord);
+        }
+
+        Ok(Matcher::with_kind(Kind::DigitSumDivisible(by), word))
+    }
+
+    /// Create a new stateful rule, that only substitutes the first multiples of a number.
+    ///
+    /// The rule works like a matcher created by `Matcher::new()`, until it has substituted
+    /// `max_hits` numbers. All following numbers aren't substituted anymore. Use it with
+    /// `fizzbuzz_stateful()`, which keeps track of the hits during the run.
+    ///
+    /// # Parameters
+    /// The `number` and `word` parameters work the same way as for `Matcher::new()`.
+    ///
+    /// The `max_hits` parameter is the number of multiples that are substituted. If it is 0, no
+    /// number is substituted.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NumberIsZero` if the `number` parameter is 0.
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use extended_fizzbuzz::{fizzbuzz_stateful_to, Matcher};
+    ///
+    /// let mut buzz = Matcher::bounded(5, 2, "Buzz").unwrap();
+    ///
+    /// let mut out = Vec::new();
+    /// fizzbuzz_stateful_to(&mut out, 4, 15, &mut [&mut buzz]).unwrap();
+    ///
+    /// assert_eq!(out, b"4\nBuzz\n6\n7\n8\n9\nBuzz\n11\n12\n13\n14\n15\n");
+    /// ```
+    pub fn bounded(number: usize, max_hits: usize, word: &str) -> Result<Bounded, MatcherError> {
+        Ok(Bounded::new(Matcher::new(number, word)?, max_hits))
+    }
+
+    /// Create the matchers of classic FizzBuzz.
+    ///
+    /// These are `3 => Fizz` and `5 => Buzz`, in that order.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = Matcher::classic();
+    ///
+    /// assert_eq!(line(3, &matchers), "Fizz");
+    /// assert_eq!(line(5, &matchers), "Buzz");
+    /// assert_eq!(line(15, &matchers), "FizzBuzz");
+    /// ```
+    pub fn classic() -> Vec<Self> {
+        vec![Matcher::fizz(), Matcher::buzz()]
+    }
+
+    /// Create the `3 => Fizz` matcher of classic FizzBuzz.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// assert_eq!(line(3, &[Matcher::fizz()]), "Fizz");
+    ///
+    /// let matchers = vec![Matcher::fizz(), Matcher::new(7, "Bazz").unwrap()];
+    /// assert_eq!(line(21, &matchers), "FizzBazz");
+    /// ```
+    pub fn fizz() -> Self {
+        // 3 is not 0, so creating the matcher can't fail.
+        Matcher::new_static(3, "Fizz").unwrap()
+    }
+
+    /// Create the `5 => Buzz` matcher of classic FizzBuzz.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// assert_eq!(line(5, &[Matcher::buzz()]), "Buzz");
+    /// assert_eq!(line(3, &[Matcher::buzz()]), "3");
+    /// ```
+    pub fn buzz() -> Self {
+        // 5 is not 0, so creating the matcher can't fail.
+        Matcher::new_static(5, "Buzz").unwrap()
+    }
+
+    /// Create multiple matchers at once, using `Matcher::new()`.
+    ///
+    /// The matchers are returned in the order of the `pairs`.
+    ///
+    /// # Errors
+    /// - Returns the error of the first pair `Matcher::new()` fails for. No further pairs are
+    ///   checked in that case.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = Matcher::many(vec![(3, "Fizz"), (5, "Buzz"), (7, "Bazz")]).unwrap();
+    ///
+    /// assert_eq!(line(105, &matchers), "FizzBuzzBazz");
+    /// assert!(Matcher::many(vec![(3, "Fizz"), (0, "Zero")]).is_err());
+    /// ```
+    pub fn many<'a, I>(pairs: I) -> Result<Vec<Self>, MatcherError>
+    where
+        I: IntoIterator<Item = (usize, &'a str)>,
+    {
+        pairs
+            .into_iter()
+            .map(|(number, word)| Matcher::new(number, word))
+            .collect()
+    }
+
+    /// Combine two matchers into one, that substitutes numbers both of them match.
+    ///
+    /// The words of the combined matchers are ignored, only `word` is used.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let odd = Matcher::with_remainder(2, 1, "Odd").unwrap();
+    /// let matchers = vec![odd.and(Matcher::prime("Prime"), "OddPrime")];
+    ///
+    /// assert_eq!(line(2, &matchers), "2");
+    /// assert_eq!(line(3, &matchers), "OddPrime");
+    /// assert_eq!(line(9, &matchers), "9");
+    /// ```
+    pub fn and(self, other: Matcher, word: &str) -> Self {
+        Matcher::with_kind(Kind::And(Box::new(self), Box::new(other)), word)
+    }
+
+    /// Combine two matchers into one, that substitutes numbers at least one of them matches.
+    ///
+    /// The words of the combined matchers are ignored, only `word` is used. Other than using both
+    /// matchers, `word` appears only once if both of them match.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{line, Matcher};
+    /// let matchers = vec![Matcher::fizz().or(Matcher::prime("Prime"), "Special")];
+    ///
+    /// assert_eq!(line(2, &matchers), "Special");
+    /// assert_eq!(line(3, &matchers), "Special");
+    /// assert_eq!(line(6, &matchers), "Special");
+    /// assert_eq!(line(8, &matchers), "8");
+    /// ```
+    pub fn or(self, other: Matcher, word: &str) -> Self {
+        Matcher::with_kind(Kind::Or(Box::new(self), Box::new(other)), word)
+    }
+
+    /// Get the number the matcher checks against.
+    ///
+    /// Returns `None` for matchers that don't check against a single number, like those created
+    /// by `Matcher::in_range()` or `Matcher::from_fn()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let matcher = Matcher::new(3, "Fizz").unwrap();
+    /// assert_eq!(matcher.number(), Some(3));
+    ///
+    /// let matcher = Matcher::exactly(42, "Answer");
+    /// assert_eq!(matcher.number(), Some(42));
+    ///
+    /// let matcher = Matcher::in_range(50, 59, "Fifties").unwrap();
+    /// assert_eq!(matcher.number(), None);
+    /// ```
+    pub fn number(&self) -> Option<usize> {
+        match self.kind {
+            Kind::Remainder { number, .. } | Kind::Exactly(number) | Kind::NotDivisible(number) => {
+                Some(number)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the divisor of a matcher created by `Matcher::new()`.
+    ///
+    /// Returns `None` for all other matchers.
+    pub(crate) fn divisor(&self) -> Option<usize> {
+        match self.kind {
+            Kind::Remainder {
+                number,
+                remainder: 0,
+                ..
+            } => Some(number),
+            _ => None,
+        }
+    }
+
+    /// Get the period after which the numbers the matcher matches repeat.
+    ///
+    /// Returns `None` for matchers that don't repeat, like `Matcher::exactly()` or
+    /// `Matcher::prime()`.
+    pub(crate) fn period(&self) -> Option<usize> {
+        match self.kind {
+            Kind::Remainder { number, .. } | Kind::NotDivisible(number) => Some(number),
+            _ => None,
+        }
+    }
+
+    /// Create a copy of the matcher, that checks against a different number.
+    ///
+    /// Everything else, including the word, is kept. The `number` is checked the same way the
+    /// constructor of the matcher checks it.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::NoNumber` if the matcher doesn't check against a single number,
+    ///   which means `number()` returns `None`.
+    /// - Returns all errors the constructor of the matcher returns for `number`.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let matcher = Matcher::new(3, "Fizz").unwrap().with_number(4).unwrap();
+    ///
+    /// assert_eq!(matcher, Matcher::new(4, "Fizz").unwrap());
+    /// assert!(matcher.with_number(0).is_err());
+    /// ```
+    pub fn with_number(&self, number: usize) -> Result<Matcher, MatcherError> {
+        let kind = match &self.kind {
+            Kind::Remainder { remainder, .. } => {
+                Matcher::remainder_cow(number, *remainder, self.word.clone())?.kind
+            }
+            Kind::Exactly(_) => Kind::Exactly(number),
+            Kind::NotDivisible(_) => Matcher::negated(number, &self.word)?.kind,
+            _ => return Err(MatcherError::NoNumber),
+        };
+
+        Ok(Matcher {
+            kind,
+            ..self.clone()
+        })
+    }
+
+    /// Create a copy of the matcher, that substitutes numbers by a different word.
+    ///
+    /// Everything else, including the rule deciding which numbers are substituted, is kept.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::EmptyWord` if the `word` parameter is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let matcher = Matcher::new(3, "Fizz").unwrap().with_word("Fuzz").unwrap();
+    ///
+    /// assert_eq!(matcher, Matcher::new(3, "Fuzz").unwrap());
+    /// assert!(matcher.with_word("").is_err());
+    /// ```
+    pub fn with_word(&self, word: &str) -> Result<Matcher, MatcherError> {
+        if word.is_empty() {
+            return Err(MatcherError::EmptyWord);
+        }
+
+        Ok(Matcher {
+            word: Cow::Owned(word.to_owned()),
+            ..self.clone()
+        })
+    }
+
+    /// Set the color the word of the matcher is printed in by `line_colored()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::{Color, Matcher};
+    /// let matcher = Matcher::new(3, "Fizz").unwrap().with_color(Color::Green);
+    ///
+    /// assert_eq!(matcher.color(), Some(Color::Green));
+    /// assert_eq!(Matcher::new(3, "Fizz").unwrap().color(), None);
+    /// ```
+    #[cfg(feature = "color")]
+    #[must_use]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Get the color the word of the matcher is printed in by `line_colored()`.
+    ///
+    /// Returns `None`, if no color was set.
+    #[cfg(feature = "color")]
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    /// Get the word the matcher substitutes numbers with.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let matcher = Matcher::new(3, "Fizz").unwrap();
+    ///
+    /// assert_eq!(matcher.word(), "Fizz");
+    /// ```
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// Describe the matcher in plain English, like `every number divisible by 3 → Fizz`.
+    ///
+    /// Other than the `Display` output, the description isn't meant to be parsed, and its exact
+    /// wording may change. It is meant for help texts and similar.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// assert_eq!(Matcher::fizz().describe(), "every number divisible by 3 → Fizz");
+    /// assert_eq!(Matcher::prime("Prime").describe(), "prime numbers → Prime");
+    /// ```
+    pub fn describe(&self) -> String {
+        let mut out = String::new();
+
+        // Writing to a `String` can't fail.
+        self.write_condition(&mut out).unwrap();
+        out.push_str(" → ");
+        out.push_str(&self.word);
+
+        out
+    }
+
+    /// Write the part of `describe()` explaining which numbers are substituted.
+    fn write_condition<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        match &self.kind {
+            Kind::Remainder {
+                number,
+                remainder: 0,
+                ..
+            } => write!(out, "every number divisible by {}", number),
+            Kind::Remainder {
+                number, remainder, ..
+            } => write!(
+                out,
+                "every number leaving a remainder of {} when divided by {}",
+                remainder, number
+            ),
+            Kind::Exactly(number) => write!(out, "the number {}", number),
+            Kind::NotDivisible(number) => write!(out, "every number not divisible by {}", number),
+            Kind::Range { low, high } => write!(out, "every number from {} to {}", low, high),
+            Kind::Predicate(_) => write!(out, "numbers matching a custom rule"),
+            Kind::Prime => write!(out, "prime numbers"),
+            Kind::PerfectSquare => write!(out, "perfect squares"),
+            Kind::Palindrome => write!(out, "palindromic numbers"),
+            Kind::Triangular => write!(out, "triangular numbers"),
+            Kind::PowerOf(base) => write!(out, "powers of {}", base),
+            Kind::ContainsDigit(digit) => {
+                write!(out, "every number containing the digit {}", digit)
+            }
+            Kind::DigitSumDivisible(by) => {
+                write!(out, "every number with a digit sum divisible by {}", by)
+            }
+            Kind::AllOf(divisors) => {
+                write!(out, "every number divisible by all of ")?;
+                write_divisors(out, divisors)
+            }
+            Kind::AnyOf(divisors) => {
+                write!(out, "every number divisible by any of ")?;
+                write_divisors(out, divisors)
+            }
+            Kind::And(left, right) => {
+                write!(out, "both (")?;
+                left.write_condition(out)?;
+                write!(out, ") and (")?;
+                right.write_condition(out)?;
+                write!(out, ")")
+            }
+            Kind::Or(left, right) => {
+                write!(out, "either (")?;
+                left.write_condition(out)?;
+                write!(out, ") or (")?;
+                right.write_condition(out)?;
+                write!(out, ")")
+            }
+        }
+    }
+
+    /// Check wether the `number` should be substituted.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let number = 3;
+    /// let matcher = Matcher::new(number, "Fizz").unwrap();
+    ///
+    /// assert_eq!(matcher.matches(number - 1), false);
+    /// assert_eq!(matcher.matches(number), true);
+    /// assert_eq!(matcher.matches(number + 1), false);
+    /// ```
+    pub fn matches(&self, number: usize) -> bool {
+        match &self.kind {
+            Kind::Remainder {
+                bitmask: Some(bitmask),
+                remainder,
+                ..
+            } => number & bitmask == *remainder,
+            Kind::Remainder {
+                number: divisor,
+                remainder,
+                ..
+            } => number % divisor == *remainder,
+            Kind::Exactly(n) => number == *n,
+            Kind::Range { low, high } => (*low..=*high).contains(&number),
+            Kind::Predicate(pred) => (pred.0)(number),
+            Kind::Prime => is_prime(number),
+            Kind::PerfectSquare => {
+                let root = number.isqrt();
+                root * root == number
+            }
+            Kind::ContainsDigit(digit) => contains_digit(number, *digit),
+            Kind::AllOf(divisors) => divisors.iter().all(|d| number % *d == 0),
+            Kind::AnyOf(divisors) => divisors.iter().any(|d| number % *d == 0),
+            Kind::NotDivisible(divisor) => number % *divisor != 0,
+            Kind::DigitSumDivisible(by) => digit_sum(number) % *by == 0,
+            Kind::Palindrome => is_palindrome(number),
+            Kind::Triangular => is_triangular(number),
+            Kind::PowerOf(base) => is_power_of(number, *base),
+            Kind::And(left, right) => left.matches(number) && right.matches(number),
+            Kind::Or(left, right) => left.matches(number) || right.matches(number),
+        }
+    }
+
+    /// Get the text the `number` should be substituted by.
+    ///
+    /// If the `number` should be substituted, returns the appropriate text. Otherwise returns an
+    /// empty string. Use `text_opt()` to tell a matcher with an empty word apart from one that
+    /// doesn't match.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let text = "Fizz";
+    /// let number = 3;
+    /// let matcher = Matcher::new(number, text).unwrap();
+    ///
+    /// assert_eq!(matcher.text(number - 1), "");
+    /// assert_eq!(matcher.text(number), text);
+    /// assert_eq!(matcher.text(number + 1), "");
+    /// ```
+    pub fn text(&self, number: usize) -> &str {
+        self.text_opt(number).unwrap_or("")
+    }
+
+    /// Get the text the `number` should be substituted by, if it should be substituted.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let matcher = Matcher::new(3, "Fizz").unwrap();
+    ///
+    /// assert_eq!(matcher.text_opt(3), Some("Fizz"));
+    /// assert_eq!(matcher.text_opt(4), None);
+    ///
+    /// let matcher = Matcher::exactly(3, "");
+    ///
+    /// assert_eq!(matcher.text_opt(3), Some(""));
+    /// assert_eq!(matcher.text_opt(4), None);
+    /// ```
+    pub fn text_opt(&self, number: usize) -> Option<&str> {
+        if self.matches(number) {
+            Some(&self.word)
+        } else {
+            None
+        }
+    }
+
+    /// Check wether the numbers in a range should be substituted.
+    ///
+    /// The returned vector contains one value per number from `from` to `to`, both inclusive. Each
+    /// value is the result of `matches()` for that number. Matchers created by `Matcher::new()`,
+    /// `Matcher::with_remainder()`, `Matcher::negated()`, `Matcher::exactly()` or
+    /// `Matcher::in_range()` fill the vector directly, without checking every number.
+    ///
+    /// # Memory
+    /// The vector contains a `bool` for every number in the range. For very large ranges this can
+    /// exhaust the available memory.
+    ///
+    /// # Errors
+    /// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than
+    ///   the `to` parameters value.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let matcher = Matcher::new(3, "Fizz").unwrap();
+    ///
+    /// assert_eq!(
+    ///     matcher.mask(2, 7).unwrap(),
+    ///     vec![false, true, false, false, true, false]
+    /// );
+    /// assert!(matcher.mask(7, 2).is_err());
+    /// ```
+    pub fn mask(&self, from: usize, to: usize) -> Result<Vec<bool>, FizzBuzzError> {
+        if from > to {
+            return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+        }
+        let len = (to - from).saturating_add(1);
+
+        let mask = match &self.kind {
+            Kind::Remainder {
+                number, remainder, ..
+            } => {
+                let mut mask = vec![false; len];
+                stride(&mut mask, from, *number, *remainder, true);
+                mask
+            }
+            Kind::NotDivisible(number) => {
+                let mut mask = vec![true; len];
+                stride(&mut mask, from, *number, 0, false);
+                mask
+            }
+            Kind::Exactly(number) => {
+                let mut mask = vec![false; len];
+                if (from..=to).contains(number) {
+                    mask[number - from] = true;
+                }
+                mask
+            }
+            Kind::Range { low, high } => {
+                let mut mask = vec![false; len];
+                let low = (*low).max(from);
+                let high = (*high).min(to);
+                if low <= high {
+                    mask[low - from..=high - from].fill(true);
+                }
+                mask
+            }
+            _ => (from..=to).map(|n| self.matches(n)).collect(),
+        };
+
+        Ok(mask)
+    }
+}
+
+/// Set every value of `mask` to `value`, whose number leaves `remainder` when divided by
+/// `divisor`.
+///
+/// The first value of `mask` belongs to the number `from`.
+fn stride(mask: &mut [bool], from: usize, divisor: usize, remainder: usize, value: bool) {
+    // The distance from `from` to the first number leaving `remainder`.
+    let offset = match from % divisor {
+        r if r <= remainder => remainder - r,
+        r => divisor - (r - remainder),
+    };
+
+    for v in mask.iter_mut().skip(offset).step_by(divisor) {
+        *v = value;
+    }
+}
+
+impl FromStr for Matcher {
+    type Err = MatcherError;
+
+    /// Parse a matcher from a string like `3=Fizz`.
+    ///
+    /// The string is split at the first `=`. The part in front of it is the `number`, everything
+    /// after it is the `word`, which can contain further `=`s.
+    ///
+    /// The `3 => Fizz` format produced by the `Display` implementation is accepted as well.
+    ///
+    /// # Errors
+    /// - Returns `MatcherError::MissingSeparator` if the string doesn't contain a `=`.
+    /// - Returns `MatcherError::InvalidNumber` if the part in front of the `=` isn't a valid
+    ///   number.
+    /// - Returns `MatcherError::NumberIsZero` if the number is 0.
+    /// - Returns `MatcherError::EmptyWord` if the word is empty, like in `3=` or `3 => `.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let matcher: Matcher = "3=Fizz".parse().unwrap();
+    ///
+    /// assert_eq!(matcher.number(), Some(3));
+    /// assert_eq!(matcher.word(), "Fizz");
+    ///
+    /// let matcher: Matcher = "5 => Buzz".parse().unwrap();
+    ///
+    /// assert_eq!(matcher.number(), Some(5));
+    /// assert_eq!(matcher.word(), "Buzz");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '=');
+        let mut number = parts.next().unwrap_or_default();
+        let mut word = parts.next().ok_or(MatcherError::MissingSeparator)?;
+
+        // A number can't end with a space, so this can only be the `Display` format.
+        if let (Some(n), Some(w)) = (number.strip_suffix(' '), word.strip_prefix("> ")) {
+            number = n;
+            word = w;
+        }
+
+        Matcher::new(number.parse()?, word)
+    }
+}
+
+/// Create a matcher from a `(number, word)` pair, using `Matcher::new()`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{Matcher, MatcherError};
+/// use std::convert::TryFrom;
+///
+/// let matchers: Result<Vec<_>, MatcherError> = vec![(3, "Fizz"), (5, "Buzz")]
+///     .into_iter()
+///     .map(Matcher::try_from)
+///     .collect();
+///
+/// assert_eq!(matchers.unwrap(), Matcher::classic());
+/// assert!(Matcher::try_from((0, "Zero")).is_err());
+/// ```
+impl TryFrom<(usize, &str)> for Matcher {
+    type Error = MatcherError;
+
+    fn try_from((number, word): (usize, &str)) -> Result<Self, Self::Error> {
+        Matcher::new(number, word)
+    }
+}
+
+impl Display for Matcher {
+    /// Format the matcher like `3 => Fizz`.
+    ///
+    /// Other kinds of matchers are formatted like this:
+    /// - `Matcher::with_remainder()` contains the remainder, like `3%1 => Off`.
+    /// - `Matcher::exactly()` is prefixed with a `=`, like `=42 => Answer`.
+    /// - `Matcher::in_range()` contains the range, like `50..=59 => Fifties`.
+    /// - `Matcher::from_fn()` uses a placeholder, like `<fn> => Even`.
+    /// - `Matcher::prime()` uses a placeholder, like `<prime> => Prime`.
+    /// - `Matcher::perfect_square()` uses a placeholder, like `<square> => Square`.
+    /// - `Matcher::palindrome()` uses a placeholder, like `<palindrome> => Palindrome`.
+    /// - `Matcher::triangular()` uses a placeholder, like `<triangular> => Triangle`.
+    /// - `Matcher::contains_digit()` contains the digit, like `<contains 3> => Fizz`.
+    /// - `Matcher::all_of()` and `Matcher::any_of()` list their divisors, like
+    ///   `<all of 3, 4> => Twelve`.
+    /// - `Matcher::negated()` is prefixed with a `!`, like `!5 => NotBuzz`.
+    /// - `Matcher::digit_sum_divisible()` contains the divisor, like `<digit sum %5> => Five`.
+    /// - `Matcher::power_of()` contains the base, like `<power of 2> => Power`.
+    /// - `Matcher::and()` and `Matcher::or()` contain both combined matchers, like
+    ///   `(3 => Fizz and <prime> => Prime) => FizzPrime`.
+    ///
+    /// The output of matchers created by `Matcher::new()` can be parsed back into an equal
+    /// `Matcher`.
+    ///
+    /// # Example
+    /// ```
+    /// # use extended_fizzbuzz::Matcher;
+    /// let matcher = Matcher::new(3, "Fizz").unwrap();
+    ///
+    /// assert_eq!(matcher.to_string(), "3 => Fizz");
+    /// assert_eq!(matcher.to_string().parse::<Matcher>().unwrap(), matcher);
+    ///
+    /// let matcher = Matcher::exactly(42, "Answer");
+    ///
+    /// assert_eq!(matcher.to_string(), "=42 => Answer");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            Kind::Remainder {
+                number,
+                remainder: 0,
+                ..
+            } => write!(f, "{} => {}", number, self.word),
+            Kind::Remainder {
+                number, remainder, ..
+            } => {
+                write!(f, "{}%{} => {}", number, remainder, self.word)
+            }
+            Kind::Exactly(number) => write!(f, "={} => {}", number, self.word),
+            Kind::NotDivisible(number) => write!(f, "!{} => {}", number, self.word),
+            Kind::Range { low, high } => write!(f, "{}..={} => {}", low, high, self.word),
+            Kind::Predicate(_) => write!(f, "<fn> => {}", self.word),
+            Kind::Prime => write!(f, "<prime> => {}", self.word),
+            Kind::PerfectSquare => write!(f, "<square> => {}", self.word),
+            Kind::Palindrome => write!(f, "<palindrome> => {}", self.word),
+            Kind::Triangular => write!(f, "<triangular> => {}", self.word),
+            Kind::PowerOf(base) => write!(f, "<power of {}> => {}", base, self.word),
+            Kind::And(left, right) => write!(f, "({} and {}) => {}", left, right, self.word),
+            Kind::Or(left, right) => write!(f, "({} or {}) => {}", left, right, self.word),
+            Kind::ContainsDigit(digit) => write!(f, "<contains {}> => {}", digit, self.word),
+            Kind::DigitSumDivisible(by) => write!(f, "<digit sum %{}> => {}", by, self.word),
+            Kind::AllOf(divisors) => {
+                write!(f, "<all of ")?;
+                write_divisors(f, divisors)?;
+                write!(f, "> => {}", self.word)
+            }
+            Kind::AnyOf(divisors) => {
+                write!(f, "<any of ")?;
+                write_divisors(f, divisors)?;
+                write!(f, "> => {}", self.word)
+            }
+        }
+    }
+}
+
+/// Check the parameters of a matcher using multiple divisors.
+fn check_divisors(divisors: &[usize], word: &str) -> Result<(), MatcherError> {
+    if divisors.is_empty() {
+        return Err(MatcherError::NoDivisors);
+    }
+    if divisors.contains(&0) {
+        return Err(MatcherError::NumberIsZero);
+    }
+    if word.is_empty() {
+        return Err(MatcherError::EmptyWord);
+    }
+
+    Ok(())
+}
+
+/// Write `divisors` separated by commas.
+fn write_divisors<W: fmt::Write>(f: &mut W, divisors: &[usize]) -> fmt::Result {
+    for (i, divisor) in divisors.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", divisor)?;
+    }
+
+    Ok(())
+}
+
+/// Check wether `number` is a prime number, using trial division.
+fn is_prime(number: usize) -> bool {
+    if number < 4 {
+        return number >= 2;
+    }
+    if number % 2 == 0 || number % 3 == 0 {
+        return false;
+    }
+
+    // All primes bigger than 3 are of the form 6k ± 1.
+    let mut divisor = 5;
+    while divisor <= number / divisor {
+        if number % divisor == 0 || number % (divisor + 2) == 0 {
+            return false;
+        }
+        divisor += 6;
+    }
+
+    true
+}
+
+/// Check wether the decimal representation of `number` contains `digit`.
+fn contains_digit(mut number: usize, digit: u8) -> bool {
+    let digit = usize::from(digit);
+
+    loop {
+        if number % 10 == digit {
+            return true;
+        }
+
+        number /= 10;
+        if number == 0 {
+            return false;
+        }
+    }
+}
+
+/// Check wether the decimal representation of `number` is a palindrome.
+///
+/// The digits are collected on the stack, so this doesn't allocate.
+fn is_palindrome(mut number: usize) -> bool {
+    // Enough digits for a 128 bit `usize`.
+    let mut digits = [0u8; 39];
+    let mut len = 0;
+
+    loop {
+        digits[len] = (number % 10) as u8;
+        len += 1;
+
+        number /= 10;
+        if number == 0 {
+            break;
+        }
+    }
+
+    let digits = &digits[..len];
+    digits.iter().eq(digits.iter().rev())
+}
+
+/// Check wether `number` is a triangular number, other than 0.
+fn is_triangular(number: usize) -> bool {
+    // If `number = k * (k + 1) / 2`, then `k` is the integer square root of `2 * number`. The
+    // calculation is done in 128 bits, so doubling can't overflow.
+    let number = number as u128;
+    let k = (2 * number).isqrt();
+
+    number != 0 && k * (k + 1) / 2 == number
+}
+
+/// Check wether `number` is a power of `base`, which has to be at least 2.
+///
+/// The powers are calculated by repeated multiplication, which stops before overflowing.
+fn is_power_of(number: usize, base: usize) -> bool {
+    let mut power = 1;
+
+    while power < number {
+        power = match power.checked_mul(base) {
+            Some(power) => power,
+            None => return false,
+        };
+    }
+
+    power == number
+}
+
+/// Calculate the sum of the decimal digits of `number`.
+fn digit_sum(mut number: usize) -> usize {
+    let mut sum = 0;
+
+    while number > 0 {
+        sum += number % 10;
+        number /= 10;
+    }
+
+    sum
+}
+
+/// All errors a `Matcher` can produce.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum MatcherError {
+    /// The `number` parameter of a `Matcher` was set to 0. This would lead to divisions by zero,
+    /// which is mathematically impossible.
+    #[error("`number` is 0, but division by 0 is impossible")]
+    NumberIsZero,
+
+    /// The `remainder` parameter of a `Matcher` isn't smaller than its `number` parameter. No
+    /// number could ever leave such a remainder.
+    #[error("`remainder` ({remainder}) isn't smaller than `number` ({number})")]
+    RemainderTooLarge { number: usize, remainder: usize },
+
+    /// The `low` parameter of a range `Matcher` has a higher value than its `high` parameter. No
+    /// valid range can be constructed.
+    #[error("`low` value ({low}) is bigger than `high` value ({high})")]
+    LowBiggerThanHigh { low: usize, high: usize },
+
+    /// The `digit` parameter of a `Matcher` is bigger than 9. Decimal numbers don't contain such
+    /// digits.
+    #[error("`digit` ({digit}) is bigger than 9")]
+    DigitTooLarge { digit: u8 },
+
+    /// The `word` parameter of a `Matcher` is empty. Numbers only matched by such a `Matcher`
+    /// would be substituted by an empty line, which is hardly ever intended.
+    #[error("`word` is empty")]
+    EmptyWord,
+
+    /// The `base` parameter of a power `Matcher` is smaller than 2. The only powers of 0 and 1
+    /// are 0 and 1 themselves.
+    #[error("`base` ({base}) is smaller than 2")]
+    BaseTooSmall { base: usize },
+
+    /// The number of a `Matcher` was changed, but it doesn't check against a single number.
+    #[error("the matcher doesn't check against a number")]
+    NoNumber,
+
+    /// A `Matcher` using multiple divisors was created without any divisors.
+    #[error("no divisors given")]
+    NoDivisors,
+
+    /// A serialized `Matcher` lacks a value its kind needs.
+    #[error("missing field `{field}`")]
+    MissingField { field: &'static str },
+
+    /// The string a `Matcher` was parsed from doesn't contain a `=`, which separates the `number`
+    /// from the `word`.
+    #[error("missing `=` between number and word")]
+    MissingSeparator,
+
+    /// The `number` part of the string a `Matcher` was parsed from isn't a valid number.
+    #[error("`number` is invalid: {0}")]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+#[cfg(feature = "serde")]
+pub(crate) mod serde_impl {
+    use super::{Kind, Matcher, MatcherError};
+    #[cfg(feature = "color")]
+    use crate::Color;
+    use alloc::borrow::ToOwned;
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// The serialized form of a `Matcher`.
+    ///
+    /// Matchers created by `Matcher::new()` are represented as `{"number":3,"word":"Fizz"}`. All
+    /// other matchers additionally contain a `kind` and the values that kind needs.
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Repr {
+        #[serde(default, skip_serializing_if = "ReprKind::is_divisor")]
+        kind: ReprKind,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        number: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        remainder: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        low: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        high: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        digit: Option<u8>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        divisors: Option<Vec<usize>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        left: Option<Box<Matcher>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        right: Option<Box<Matcher>>,
+        word: String,
+        #[cfg(feature = "color")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        color: Option<Color>,
+    }
+
+    #[derive(Clone, Copy, Default, Deserialize, PartialEq, Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum ReprKind {
+        #[default]
+        Divisor,
+        Exactly,
+        Range,
+        Prime,
+        PerfectSquare,
+        ContainsDigit,
+        AllOf,
+        AnyOf,
+        Negated,
+        DigitSum,
+        Palindrome,
+        Triangular,
+        PowerOf,
+        And,
+        Or,
+    }
+
+    impl ReprKind {
+        fn is_divisor(&self) -> bool {
+            *self == ReprKind::Divisor
+        }
+    }
+
+    impl Repr {
+        fn new(kind: ReprKind, word: &str) -> Self {
+            Repr {
+                kind,
+                number: None,
+                remainder: None,
+                low: None,
+                high: None,
+                digit: None,
+                divisors: None,
+                left: None,
+                right: None,
+                word: word.to_owned(),
+                #[cfg(feature = "color")]
+                color: None,
+            }
+        }
+    }
+
+    fn required<T>(value: Option<T>, field: &'static str) -> Result<T, MatcherError> {
+        value.ok_or(MatcherError::MissingField { field })
+    }
+
+    impl Serialize for Matcher {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            #[allow(unused_mut)]
+            let mut repr = match &self.kind {
+                Kind::Remainder {
+                    number, remainder, ..
+                } => Repr {
+                    number: Some(*number),
+                    remainder: Some(*remainder).filter(|r| *r != 0),
+                    ..Repr::new(ReprKind::Divisor, &self.word)
+                },
+                Kind::Exactly(number) => Repr {
+                    number: Some(*number),
+                    ..Repr::new(ReprKind::Exactly, &self.word)
+                },
+                Kind::Range { low, high } => Repr {
+                    low: Some(*low),
+                    high: Some(*high),
+                    ..Repr::new(ReprKind::Range, &self.word)
+                },
+                Kind::Prime => Repr::new(ReprKind::Prime, &self.word),
+                Kind::PerfectSquare => Repr::new(ReprKind::PerfectSquare, &self.word),
+                Kind::Palindrome => Repr::new(ReprKind::Palindrome, &self.word),
+                Kind::Triangular => Repr::new(ReprKind::Triangular, &self.word),
+                Kind::PowerOf(base) => Repr {
+                    number: Some(*base),
+                    ..Repr::new(ReprKind::PowerOf, &self.word)
+                },
+                Kind::And(left, right) => Repr {
+                    left: Some(left.clone()),
+                    right: Some(right.clone()),
+                    ..Repr::new(ReprKind::And, &self.word)
+                },
+                Kind::Or(left, right) => Repr {
+                    left: Some(left.clone()),
+                    right: Some(right.clone()),
+                    ..Repr::new(ReprKind::Or, &self.word)
+                },
+                Kind::ContainsDigit(digit) => Repr {
+                    digit: Some(*digit),
+                    ..Repr::new(ReprKind::ContainsDigit, &self.word)
+                },
+                Kind::AllOf(divisors) => Repr {
+                    divisors: Some(divisors.clone()),
+                    ..Repr::new(ReprKind::AllOf, &self.word)
+                },
+                Kind::AnyOf(divisors) => Repr {
+                    divisors: Some(divisors.clone()),
+                    ..Repr::new(ReprKind::AnyOf, &self.word)
+                },
+                Kind::NotDivisible(number) => Repr {
+                    number: Some(*number),
+                    ..Repr::new(ReprKind::Negated, &self.word)
+                },
+                Kind::DigitSumDivisible(by) => Repr {
+                    number: Some(*by),
+                    ..Repr::new(ReprKind::DigitSum, &self.word)
+                },
+                Kind::Predicate(_) => {
+                    return Err(ser::Error::custom(
+                        "matchers created by `Matcher::from_fn()` can't be serialized",
+                    ))
+                }
+            };
+
+            #[cfg(feature = "color")]
+            {
+                repr.color = self.color;
+            }
+
+            repr.serialize(serializer)
+        }
+    }
+
+    impl Repr {
+        /// Create the matcher this representation describes.
+        ///
+        /// # Errors
+        /// - Returns `MatcherError::MissingField` if a value the kind needs is missing.
+        /// - Returns all errors the constructor of the kind returns.
+        pub(crate) fn into_matcher(self) -> Result<Matcher, MatcherError> {
+            let word = &self.word;
+
+            #[allow(unused_mut)]
+            let mut matcher = match self.kind {
+                ReprKind::Divisor => Matcher::with_remainder(
+                    required(self.number, "number")?,
+                    self.remainder.unwrap_or(0),
+                    word,
+                ),
+                ReprKind::Exactly => Ok(Matcher::exactly(required(self.number, "number")?, word)),
+                ReprKind::Range => Matcher::in_range(
+                    required(self.low, "low")?,
+                    required(self.high, "high")?,
+                    word,
+                ),
+                ReprKind::Prime => Ok(Matcher::prime(word)),
+                ReprKind::PerfectSquare => Ok(Matcher::perfect_square(word)),
+                ReprKind::Palindrome => Ok(Matcher::palindrome(word)),
+                ReprKind::Triangular => Ok(Matcher::triangular(word)),
+                ReprKind::PowerOf => Matcher::power_of(required(self.number, "number")?, word),
+                ReprKind::And => {
+                    Ok(required(self.left, "left")?.and(*required(self.right, "right")?, word))
+                }
+                ReprKind::Or => {
+                    Ok(required(self.left, "left")?.or(*required(self.right, "right")?, word))
+                }
+                ReprKind::ContainsDigit => {
+                    Matcher::contains_digit(required(self.digit, "digit")?, word)
+                }
+                ReprKind::AllOf => Matcher::all_of(&required(self.divisors, "divisors")?, word),
+                ReprKind::AnyOf => Matcher::any_of(&required(self.divisors, "divisors")?, word),
+                ReprKind::Negated => Matcher::negated(required(self.number, "number")?, word),
+                ReprKind::DigitSum => {
+                    Matcher::digit_sum_divisible(required(self.number, "number")?, word)
+                }
+            }?;
+
+            #[cfg(feature = "color")]
+            {
+                matcher.color = self.color;
+            }
+
+            Ok(matcher)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Matcher {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Repr::deserialize(deserializer)?
+                .into_matcher()
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::random;
+
+    #[test]
+    fn new_normal() {
+        let word = "Test";
+        let mut number = 0;
+        while number == 0 {
+            number = random();
+        }
+
+        let matcher = Matcher::new(number, word).unwrap();
+        assert!(matches!(
+            matcher.kind,
+            Kind::Remainder {
+                number: n,
+                remainder: 0,
+                ..
+            } if n == number
+        ));
+        assert_eq!(matcher.word, word.to_string());
+    }
+
+    #[test]
+    fn power_of_two_bitmask() {
+        for (number, bitmask) in [
+            (1, Some(0)),
+            (2, Some(1)),
+            (8, Some(7)),
+            (3, None),
+            (12, None),
+        ]
+        .iter()
+        .cloned()
+        {
+            let matcher = Matcher::new(number, "Test").unwrap();
+            assert!(matches!(
+                matcher.kind,
+                Kind::Remainder { bitmask: b, .. } if b == bitmask
+            ));
+        }
+
+        let matcher = Matcher::new(1 << (usize::BITS - 1), "Top").unwrap();
+        assert!(matches!(
+            matcher.kind,
+            Kind::Remainder { bitmask: Some(b), .. } if b == usize::MAX >> 1
+        ));
+    }
+
+    #[test]
+    fn power_of_two_equals_modulo() {
+        for divisor in [2, 4, 8, 16].iter().cloned() {
+            for remainder in 0..divisor {
+                let matcher = Matcher::with_remainder(divisor, remainder, "Test").unwrap();
+                let numbers = (0..1000)
+                    .chain(usize::MAX - 100..=usize::MAX)
+                    .chain((0..100).map(|_| random()));
+
+                for n in numbers {
+                    assert_eq!(
+                        matcher.matches(n),
+                        n % divisor == remainder,
+                        "{} for {}",
+                        n,
+                        matcher
+                    );
+                }
+            }
+        }
+
+        // Changing the number recalculates the bitmask.
+        let matcher = Matcher::new(4, "Four").unwrap().with_number(6).unwrap();
+        assert!(matcher.matches(6));
+        assert!(!matcher.matches(4));
+        assert_eq!(matcher, Matcher::new(6, "Four").unwrap());
+    }
+
+    #[test]
+    fn accessors() {
+        let word = "Test";
+        let mut number = 0;
+        while number == 0 {
+            number = random();
+        }
+
+        let matcher = Matcher::new(number, word).unwrap();
+        assert_eq!(matcher.number(), Some(number));
+        assert_eq!(matcher.word(), word);
+    }
+
+    #[test]
+    fn new_zero() -> Result<(), String> {
+        let word = "Test";
+        let number = 0;
+
+        let matcher_res = Matcher::new(number, word);
+        match matcher_res {
+            Err(e) => match e {
+                MatcherError::NumberIsZero => Ok(()),
+                _ => Err("Wrong error kind".to_string()),
+            },
+            _ => Err("Wrongfully succeeded".to_string()),
+        }
+    }
+
+    #[test]
+    fn matches_normal() {
+        let word = "Test";
+        let mut number = 0;
+        while number == 0 || number == 1 {
+            number = random();
+        }
+
+        let matcher = Matcher::new(number, word).unwrap();
+
+        assert!(!matcher.matches(number - 1));
+        assert!(matcher.matches(number));
+        assert!(!matcher.matches(number + 1));
+    }
+
+    #[test]
+    fn matches_one() {
+        let word = "Test";
+        let number = 1;
+
+        let matcher = Matcher::new(number, word).unwrap();
+
+        assert!(matcher.matches(number - 1));
+        assert!(matcher.matches(number));
+        assert!(matcher.matches(number + 1));
+    }
+
+    #[test]
+    fn text_normal() {
+        let word = "Test";
+        let mut number = 0;
+        while number == 0 || number == 1 {
+            number = random();
+        }
+
+        let matcher = Matcher::new(number, word).unwrap();
+
+        assert_eq!(matcher.text(number - 1), "");
+        assert_eq!(matcher.text(number), word);
+        assert_eq!(matcher.text(number + 1), "");
+    }
+
+    #[test]
+    fn text_one() {
+        let word = "Test";
+        let number = 1;
+
+        let matcher = Matcher::new(number, word).unwrap();
+
+        assert_eq!(matcher.text(number - 1), word);
+        assert_eq!(matcher.text(number), word);
+        assert_eq!(matcher.text(number + 1), word);
+    }
+
+    #[test]
+    fn text_opt_empty_word() {
+        let matcher = Matcher::exactly(7, "");
+
+        assert_eq!(matcher.text_opt(6), None);
+        assert_eq!(matcher.text_opt(7), Some(""));
+        assert_eq!(matcher.text_opt(8), None);
+        assert_eq!(matcher.text(6), matcher.text(7));
+
+        let matcher = Matcher::new(7, "Bazz").unwrap();
+
+        assert_eq!(matcher.text_opt(6), None);
+        assert_eq!(matcher.text_opt(7), Some("Bazz"));
+    }
+
+    #[test]
+    fn sort_by_divisor() {
+        let mut matchers = [
+            Matcher::new(7, "Bazz").unwrap(),
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+            Matcher::new(3, "Bizz").unwrap(),
+            Matcher::prime("Prime"),
+        ];
+        matchers.sort();
+
+        let numbers: Vec<_> = matchers.iter().map(|m| m.number()).collect();
+        assert_eq!(numbers, vec![None, Some(3), Some(3), Some(5), Some(7)]);
+
+        let words: Vec<_> = matchers.iter().map(|m| m.word()).collect();
+        assert_eq!(words, vec!["Prime", "Bizz", "Fizz", "Buzz", "Bazz"]);
+    }
+
+    #[test]
+    fn ord_consistent_with_eq() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::with_remainder(3, 1, "Fizz").unwrap(),
+            Matcher::exactly(3, "Fizz"),
+            Matcher::prime("Fizz"),
+            Matcher::perfect_square("Fizz"),
+            Matcher::from_fn("Fizz", |n| n == 3),
+            Matcher::from_fn("Fizz", |n| n == 3),
+        ];
+
+        for a in &matchers {
+            for b in &matchers {
+                assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+            }
+        }
+    }
+
+    #[test]
+    fn new_static_same_as_new() {
+        let borrowed = Matcher::new_static(3, "Fizz").unwrap();
+        let owned = Matcher::new(3, &String::from("Fizz")).unwrap();
+
+        assert!(matches!(borrowed.word, Cow::Borrowed(_)));
+        assert!(matches!(owned.word, Cow::Owned(_)));
+
+        assert_eq!(borrowed, owned);
+        assert_eq!(borrowed.word(), owned.word());
+        assert_eq!(borrowed.to_string(), owned.to_string());
+        for i in 0..100 {
+            assert_eq!(borrowed.matches(i), owned.matches(i));
+            assert_eq!(borrowed.text_opt(i), owned.text_opt(i));
+        }
+
+        assert!(matches!(
+            Matcher::new_static(0, "Fizz"),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            Matcher::new_static(3, ""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    fn all_of_normal() {
+        let matcher = Matcher::all_of(&[3, 4], "Twelve").unwrap();
+
+        for i in [12, 24, 36, 0].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [3, 4, 6, 8, 9, 13].iter() {
+            assert!(!matcher.matches(*i));
+        }
+
+        assert_eq!(matcher.number(), None);
+        assert_eq!(matcher.divisor(), None);
+        assert_eq!(crate::line(12, &[matcher]), "Twelve");
+    }
+
+    #[test]
+    fn all_of_invalid() {
+        assert!(matches!(
+            Matcher::all_of(&[], "Never"),
+            Err(MatcherError::NoDivisors)
+        ));
+        assert!(matches!(
+            Matcher::all_of(&[3, 0], "Zero"),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            Matcher::all_of(&[3, 4], ""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    fn any_of_normal() {
+        let matcher = Matcher::any_of(&[3, 7], "Flag").unwrap();
+
+        for i in [3, 6, 7, 14, 21, 0].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [1, 2, 4, 5, 8, 10].iter() {
+            assert!(!matcher.matches(*i));
+        }
+
+        assert_eq!(matcher.number(), None);
+        assert_eq!(matcher.divisor(), None);
+        assert_eq!(crate::line(21, &[matcher]), "Flag");
+    }
+
+    #[test]
+    fn any_of_invalid() {
+        assert!(matches!(
+            Matcher::any_of(&[], "Never"),
+            Err(MatcherError::NoDivisors)
+        ));
+        assert!(matches!(
+            Matcher::any_of(&[0, 7], "Zero"),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            Matcher::any_of(&[3, 7], ""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    fn negated_normal() {
+        let matcher = Matcher::negated(5, "NotBuzz").unwrap();
+
+        for i in [1, 2, 3, 4, 6, 11].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [0, 5, 10, 15].iter() {
+            assert!(!matcher.matches(*i));
+        }
+
+        assert_eq!(matcher.number(), Some(5));
+        assert_eq!(matcher.divisor(), None);
+        assert_eq!(matcher.period(), Some(5));
+    }
+
+    #[test]
+    fn negated_one_never_matches() {
+        let matcher = Matcher::negated(1, "Nothing").unwrap();
+
+        for i in 0..100 {
+            assert!(!matcher.matches(i));
+        }
+        assert!(!matcher.matches(usize::MAX));
+    }
+
+    #[test]
+    fn negated_invalid() {
+        assert!(matches!(
+            Matcher::negated(0, "Zero"),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            Matcher::negated(5, ""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    fn new_owned_same_as_new() {
+        let word = String::from("x");
+        let address = word.as_ptr();
+
+        let owned = Matcher::new_owned(2, word).unwrap();
+        let copied = Matcher::new(2, "x").unwrap();
+
+        // The string is moved in, not copied.
+        assert_eq!(owned.word().as_ptr(), address);
+
+        assert_eq!(owned, copied);
+        assert_eq!(owned.to_string(), copied.to_string());
+        for i in 0..10 {
+            assert_eq!(owned.text_opt(i), copied.text_opt(i));
+        }
+
+        assert!(matches!(
+            Matcher::new_owned(0, String::from("x")),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            Matcher::new_owned(2, String::new()),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    fn new_empty_word() {
+        assert!(matches!(Matcher::new(3, ""), Err(MatcherError::EmptyWord)));
+        assert!(matches!(
+            Matcher::with_remainder(3, 1, ""),
+            Err(MatcherError::EmptyWord)
+        ));
+        assert!(matches!(
+            "3=".parse::<Matcher>(),
+            Err(MatcherError::EmptyWord)
+        ));
+        assert!(matches!(
+            "3 => ".parse::<Matcher>(),
+            Err(MatcherError::EmptyWord)
+        ));
+
+        assert_eq!(Matcher::new(3, " ").unwrap().word(), " ");
+    }
+
+    #[test]
+    fn many_valid() {
+        let matchers = Matcher::many(vec![(5, "Buzz"), (3, "Fizz"), (7, "Bazz")]).unwrap();
+
+        assert_eq!(
+            matchers,
+            vec![
+                Matcher::new(5, "Buzz").unwrap(),
+                Matcher::new(3, "Fizz").unwrap(),
+                Matcher::new(7, "Bazz").unwrap(),
+            ]
+        );
+        assert!(Matcher::many(Vec::new()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn many_zero_divisor() {
+        let pairs = vec![(3, "Fizz"), (0, "Zero"), (5, "")];
+
+        // The first error wins.
+        assert!(matches!(
+            Matcher::many(pairs),
+            Err(MatcherError::NumberIsZero)
+        ));
+    }
+
+    #[test]
+    fn try_from_pairs() {
+        assert_eq!(
+            Matcher::try_from((3, "Fizz")).unwrap(),
+            Matcher::new(3, "Fizz").unwrap()
+        );
+
+        let matchers: Result<Vec<_>, _> = [(3, "Fizz"), (0, "Bad"), (5, "Buzz")]
+            .iter()
+            .copied()
+            .map(Matcher::try_from)
+            .collect();
+        assert!(matches!(matchers, Err(MatcherError::NumberIsZero)));
+    }
+
+    #[test]
+    fn from_str_normal() {
+        let matcher: Matcher = "15=FizzBuzz".parse().unwrap();
+
+        assert_eq!(matcher.number(), Some(15));
+        assert_eq!(matcher.word, "FizzBuzz");
+    }
+
+    #[test]
+    fn from_str_separator_in_word() {
+        let matcher: Matcher = "7=a=b".parse().unwrap();
+
+        assert_eq!(matcher.number(), Some(7));
+        assert_eq!(matcher.word, "a=b");
+    }
+
+    #[test]
+    fn from_str_zero() {
+        let res = "0=Zero".parse::<Matcher>();
+        assert!(matches!(res, Err(MatcherError::NumberIsZero)));
+    }
+
+    #[test]
+    fn from_str_invalid_number() {
+        let res = "abc=x".parse::<Matcher>();
+        assert!(matches!(res, Err(MatcherError::InvalidNumber(_))));
+
+        let res = "=x".parse::<Matcher>();
+        assert!(matches!(res, Err(MatcherError::InvalidNumber(_))));
+    }
+
+    #[test]
+    fn from_str_missing_separator() {
+        let res = "3Fizz".parse::<Matcher>();
+        assert!(matches!(res, Err(MatcherError::MissingSeparator)));
+    }
+
+    #[test]
+    fn clone_eq() {
+        let matcher = Matcher::new(3, "Fizz").unwrap();
+
+        assert_eq!(matcher.clone(), matcher);
+        assert_ne!(matcher, Matcher::new(3, "Buzz").unwrap());
+        assert_ne!(matcher, Matcher::new(5, "Fizz").unwrap());
+    }
+
+    #[test]
+    fn hash_set() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(Matcher::new(3, "Fizz").unwrap()));
+        assert!(set.insert(Matcher::new(5, "Buzz").unwrap()));
+        assert!(!set.insert(Matcher::new(3, "Fizz").unwrap()));
+
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn display_normal() {
+        let matcher = Matcher::new(15, "FizzBuzz").unwrap();
+
+        assert_eq!(matcher.to_string(), "15 => FizzBuzz");
+        assert_eq!(format!("{}", matcher), "15 => FizzBuzz");
+    }
+
+    #[test]
+    fn display_all_of() {
+        let matcher = Matcher::all_of(&[3, 4], "Twelve").unwrap();
+
+        assert_eq!(matcher.to_string(), "<all of 3, 4> => Twelve");
+    }
+
+    #[test]
+    fn display_any_of() {
+        let matcher = Matcher::any_of(&[3, 7], "Flag").unwrap();
+
+        assert_eq!(matcher.to_string(), "<any of 3, 7> => Flag");
+    }
+
+    #[test]
+    fn display_negated() {
+        let matcher = Matcher::negated(5, "NotBuzz").unwrap();
+
+        assert_eq!(matcher.to_string(), "!5 => NotBuzz");
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let words = ["Fizz", "a=b", "=> x", " ", "> y"];
+
+        for word in words.iter() {
+            let mut number = 0;
+            while number == 0 {
+                number = random();
+            }
+
+            let matcher = Matcher::new(number, word).unwrap();
+            let parsed: Matcher = matcher.to_string().parse().unwrap();
+
+            assert_eq!(parsed, matcher);
+        }
+    }
+
+    #[test]
+    fn classic() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(matchers.len(), 2);
+        assert_eq!(matchers[0], Matcher::new(3, "Fizz").unwrap());
+        assert_eq!(matchers[1], Matcher::new(5, "Buzz").unwrap());
+    }
+
+    #[test]
+    fn fizz_buzz() {
+        assert_eq!(Matcher::fizz(), Matcher::new(3, "Fizz").unwrap());
+        assert_eq!(Matcher::buzz(), Matcher::new(5, "Buzz").unwrap());
+        assert_eq!(vec![Matcher::fizz(), Matcher::buzz()], Matcher::classic());
+    }
+
+    #[test]
+    fn exactly_normal() {
+        let matcher = Matcher::exactly(42, "Answer");
+
+        assert_eq!(matcher.number(), Some(42));
+        assert_eq!(matcher.word(), "Answer");
+
+        for i in 0..1000 {
+            assert_eq!(matcher.matches(i), i == 42);
+        }
+        assert_eq!(matcher.text(42), "Answer");
+        assert_eq!(matcher.text(84), "");
+    }
+
+    #[test]
+    fn exactly_zero() {
+        let matcher = Matcher::exactly(0, "Zero");
+
+        assert!(matcher.matches(0));
+        assert!(!matcher.matches(1));
+        assert!(!matcher.matches(usize::MAX));
+    }
+
+    #[test]
+    fn exactly_not_equal_to_divisor() {
+        assert_ne!(
+            Matcher::exactly(3, "Fizz"),
+            Matcher::new(3, "Fizz").unwrap()
+        );
+    }
+
+    #[test]
+    fn with_remainder_normal() {
+        let matcher = Matcher::with_remainder(3, 1, "Off").unwrap();
+
+        for i in [1, 4, 7, 10].iter() {
+            assert!(matcher.matches(*i));
+            assert_eq!(matcher.text(*i), "Off");
+        }
+        for i in [0, 3, 6, 9, 12].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        for i in [2, 5, 8, 11].iter() {
+            assert!(!matcher.matches(*i));
+        }
+    }
+
+    #[test]
+    fn with_remainder_zero() {
+        let mut number = 0;
+        while number == 0 {
+            number = random();
+        }
+
+        assert_eq!(
+            Matcher::with_remainder(number, 0, "Test").unwrap(),
+            Matcher::new(number, "Test").unwrap()
+        );
+    }
+
+    #[test]
+    fn with_remainder_too_large() {
+        assert!(matches!(
+            Matcher::with_remainder(3, 3, "Test"),
+            Err(MatcherError::RemainderTooLarge {
+                number: 3,
+                remainder: 3
+            })
+        ));
+        assert!(matches!(
+            Matcher::with_remainder(3, 7, "Test"),
+            Err(MatcherError::RemainderTooLarge { .. })
+        ));
+        assert!(matches!(
+            Matcher::with_remainder(0, 0, "Test"),
+            Err(MatcherError::NumberIsZero)
+        ));
+    }
+
+    #[test]
+    fn with_remainder_display() {
+        let matcher = Matcher::with_remainder(3, 1, "Off").unwrap();
+        assert_eq!(matcher.to_string(), "3%1 => Off");
+    }
+
+    #[test]
+    fn in_range_normal() {
+        let low = 50;
+        let high = 59;
+        let matcher = Matcher::in_range(low, high, "Fifties").unwrap();
+
+        assert_eq!(matcher.number(), None);
+        assert!(!matcher.matches(low - 1));
+        assert!(matcher.matches(low));
+        assert!(matcher.matches(55));
+        assert!(matcher.matches(high));
+        assert!(!matcher.matches(high + 1));
+        assert_eq!(matcher.text(high), "Fifties");
+        assert_eq!(matcher.text(high + 1), "");
+    }
+
+    #[test]
+    fn in_range_single() {
+        let mut number: usize = 0;
+        while number == 0 || number == usize::MAX {
+            number = random();
+        }
+
+        let matcher = Matcher::in_range(number, number, "Test").unwrap();
+
+        assert!(!matcher.matches(number - 1));
+        assert!(matcher.matches(number));
+        assert!(!matcher.matches(number + 1));
+    }
+
+    #[test]
+    fn in_range_bounds() {
+        let matcher = Matcher::in_range(0, usize::MAX, "All").unwrap();
+
+        assert!(matcher.matches(0));
+        assert!(matcher.matches(usize::MAX));
+    }
+
+    #[test]
+    fn in_range_low_bigger_than_high() {
+        assert!(matches!(
+            Matcher::in_range(60, 50, "Test"),
+            Err(MatcherError::LowBiggerThanHigh { low: 60, high: 50 })
+        ));
+    }
+
+    #[test]
+    fn in_range_display() {
+        let matcher = Matcher::in_range(50, 59, "Fifties").unwrap();
+        assert_eq!(matcher.to_string(), "50..=59 => Fifties");
+    }
+
+    #[test]
+    fn from_fn_prime() {
+        let matcher = Matcher::from_fn("Prime", |n| n >= 2 && (2..n).all(|d| n % d != 0));
+
+        for i in [2, 3, 5, 7, 11, 13].iter() {
+            assert!(matcher.matches(*i));
+            assert_eq!(matcher.text(*i), "Prime");
+        }
+        for i in [0, 1, 4, 6, 8, 9, 10, 12].iter() {
+            assert!(!matcher.matches(*i));
+            assert_eq!(matcher.text(*i), "");
+        }
+        assert_eq!(matcher.number(), None);
+    }
+
+    #[test]
+    fn from_fn_eq() {
+        let matcher = Matcher::from_fn("Even", |n| n % 2 == 0);
+        let other = Matcher::from_fn("Even", |n| n % 2 == 0);
+
+        assert_eq!(matcher.clone(), matcher);
+        assert_ne!(matcher, other);
+    }
+
+    #[test]
+    fn from_fn_display() {
+        let matcher = Matcher::from_fn("Even", |n| n % 2 == 0);
+        assert_eq!(matcher.to_string(), "<fn> => Even");
+    }
+
+    #[test]
+    fn prime_normal() {
+        let matcher = Matcher::prime("Prime");
+
+        for i in [2, 3, 5, 7, 11, 13, 97, 7919].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [0, 1, 4, 9, 25, 49, 91, 7917].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        assert_eq!(matcher.number(), None);
+    }
+
+    #[test]
+    fn prime_equals_naive() {
+        let naive = |n: usize| n >= 2 && (2..n).all(|d| n % d != 0);
+
+        for i in 0..2000 {
+            assert_eq!(is_prime(i), naive(i), "{}", i);
+        }
+    }
+
+    #[test]
+    fn prime_large() {
+        // The biggest prime below 2^32 and the square of a prime close to it.
+        assert!(is_prime(4_294_967_291));
+        assert!(!is_prime(65_521 * 65_521));
+        assert!(!is_prime(usize::MAX));
+    }
+
+    #[test]
+    fn perfect_square_normal() {
+        let matcher = Matcher::perfect_square("Square");
+
+        for i in [0, 1, 4, 9, 16, 25, 10_000].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [2, 3, 5, 8, 10, 15, 17, 24, 26, 9_999].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        assert_eq!(matcher.number(), None);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn perfect_square_large() {
+        let matcher = Matcher::perfect_square("Square");
+
+        let n: usize = 1 << 32;
+        assert!(matcher.matches(n));
+        assert!(!matcher.matches(n - 1));
+        assert!(!matcher.matches(n + 1));
+
+        // Squares near the top of the range, where `f64` can't represent every integer.
+        let root: usize = (1 << 32) - 1;
+        assert!(matcher.matches(root * root));
+        assert!(!matcher.matches(root * root - 1));
+        assert!(!matcher.matches(root * root + 1));
+        assert!(!matcher.matches(usize::MAX));
+
+        let root: usize = 3_037_000_499;
+        assert!(matcher.matches(root * root));
+        assert!(!matcher.matches(root * root + 1));
+    }
+
+    #[test]
+    fn contains_digit_normal() {
+        let matcher = Matcher::contains_digit(3, "Fizz").unwrap();
+
+        for i in [3, 13, 23, 30, 31, 39, 103, 1_000_003].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [0, 1, 12, 20, 24, 40, 1_000_000].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        assert_eq!(matcher.number(), None);
+    }
+
+    #[test]
+    fn contains_digit_zero() {
+        let matcher = Matcher::contains_digit(0, "Zero").unwrap();
+
+        assert!(matcher.matches(0));
+        assert!(matcher.matches(10));
+        assert!(matcher.matches(101));
+        assert!(!matcher.matches(1));
+        assert!(!matcher.matches(99));
+    }
+
+    #[test]
+    fn contains_digit_equals_to_string() {
+        for _ in 0..100 {
+            let number: usize = random();
+            let digit: u8 = random::<u8>() % 10;
+
+            let matcher = Matcher::contains_digit(digit, "Test").unwrap();
+            assert_eq!(
+                matcher.matches(number),
+                number.to_string().contains(char::from(b'0' + digit))
+            );
+        }
+    }
+
+    #[test]
+    fn contains_digit_too_large() {
+        assert!(matches!(
+            Matcher::contains_digit(10, "Test"),
+            Err(MatcherError::DigitTooLarge { digit: 10 })
+        ));
+    }
+
+    #[test]
+    fn digit_sum_divisible_normal() {
+        let matcher = Matcher::digit_sum_divisible(5, "Five").unwrap();
+
+        for i in [0, 5, 14, 23, 50, 69, 1_000_004].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [1, 10, 15, 24, 56].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        assert_eq!(matcher.number(), None);
+        assert_eq!(matcher.to_string(), "<digit sum %5> => Five");
+    }
+
+    #[test]
+    fn digit_sum_divisible_by_three() {
+        let matcher = Matcher::digit_sum_divisible(3, "Fizz").unwrap();
+
+        for _ in 0..100 {
+            let number: usize = random();
+            assert_eq!(matcher.matches(number), number % 3 == 0);
+        }
+    }
+
+    #[test]
+    fn digit_sum_divisible_invalid() {
+        assert!(matches!(
+            Matcher::digit_sum_divisible(0, "Zero"),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            Matcher::digit_sum_divisible(5, ""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    fn palindrome_normal() {
+        let matcher = Matcher::palindrome("Palindrome");
+
+        for i in [0, 7, 9, 11, 22, 121, 1221, 12_321].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [10, 12, 122, 1231, 100].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        assert_eq!(matcher.number(), None);
+        assert_eq!(matcher.to_string(), "<palindrome> => Palindrome");
+    }
+
+    #[test]
+    fn palindrome_equals_to_string() {
+        let matcher = Matcher::palindrome("Palindrome");
+        let naive = |n: usize| {
+            let s = n.to_string();
+            s.chars().eq(s.chars().rev())
+        };
+
+        for i in (0..10_000).chain(usize::MAX - 100..=usize::MAX) {
+            assert_eq!(matcher.matches(i), naive(i), "mismatch for {}", i);
+        }
+    }
+
+    #[test]
+    fn palindrome_composes() {
+        let matchers = vec![
+            Matcher::new(11, "Eleven").unwrap(),
+            Matcher::palindrome("Palindrome"),
+        ];
+
+        assert_eq!(crate::line(22, &matchers), "ElevenPalindrome");
+        assert_eq!(crate::line(121, &matchers), "ElevenPalindrome");
+        assert_eq!(crate::line(131, &matchers), "Palindrome");
+        assert_eq!(crate::line(132, &matchers), "Eleven");
+    }
+
+    #[test]
+    fn mask_equals_matches() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(1, "One").unwrap(),
+            Matcher::new(250, "Large").unwrap(),
+            Matcher::with_remainder(7, 0, "Seven").unwrap(),
+            Matcher::with_remainder(7, 6, "Six").unwrap(),
+            Matcher::with_remainder(10, 3, "Three").unwrap(),
+            Matcher::negated(4, "NotFour").unwrap(),
+            Matcher::exactly(42, "Answer"),
+            Matcher::exactly(0, "Zero"),
+            Matcher::in_range(50, 59, "Fifties").unwrap(),
+            Matcher::in_range(0, 10, "Small").unwrap(),
+            Matcher::prime("Prime"),
+        ];
+        let ranges = [(0, 0), (0, 100), (1, 15), (13, 57), (55, 200), (101, 120)];
+
+        for m in matchers.iter() {
+            for (from, to) in ranges.iter() {
+                let naive: Vec<_> = (*from..=*to).map(|n| m.matches(n)).collect();
+                assert_eq!(
+                    m.mask(*from, *to).unwrap(),
+                    naive,
+                    "{} on {}..={}",
+                    m,
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mask_random() {
+        for _ in 0..100 {
+            let divisor = random::<usize>() % 20 + 1;
+            let m = Matcher::with_remainder(divisor, random::<usize>() % divisor, "X").unwrap();
+            let from = random::<usize>() % 1000;
+            let to = from + random::<usize>() % 100;
+
+            let naive: Vec<_> = (from..=to).map(|n| m.matches(n)).collect();
+            assert_eq!(m.mask(from, to).unwrap(), naive);
+        }
+    }
+
+    #[test]
+    fn mask_near_max() {
+        let m = Matcher::new(2, "Even").unwrap();
+
+        assert_eq!(
+            m.mask(usize::MAX - 2, usize::MAX).unwrap(),
+            vec![false, true, false]
+        );
+    }
+
+    #[test]
+    fn mask_from_bigger_than_to() {
+        assert!(matches!(
+            Matcher::fizz().mask(5, 4),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 5, to: 4 })
+        ));
+    }
+
+    #[test]
+    fn describe_divisor() {
+        assert_eq!(
+            Matcher::new(3, "Fizz").unwrap().describe(),
+            "every number divisible by 3 → Fizz"
+        );
+        assert_eq!(
+            Matcher::with_remainder(3, 1, "Off").unwrap().describe(),
+            "every number leaving a remainder of 1 when divided by 3 → Off"
+        );
+        assert_eq!(
+            Matcher::all_of(&[3, 4], "Twelve").unwrap().describe(),
+            "every number divisible by all of 3, 4 → Twelve"
+        );
+    }
+
+    #[test]
+    fn describe_exact() {
+        assert_eq!(
+            Matcher::exactly(42, "Answer").describe(),
+            "the number 42 → Answer"
+        );
+        assert_eq!(
+            Matcher::exactly(0, "Zero").describe(),
+            "the number 0 → Zero"
+        );
+    }
+
+    #[test]
+    fn describe_other() {
+        assert_eq!(
+            Matcher::in_range(50, 59, "Fifties").unwrap().describe(),
+            "every number from 50 to 59 → Fifties"
+        );
+        assert_eq!(
+            Matcher::from_fn("Even", |n| n % 2 == 0).describe(),
+            "numbers matching a custom rule → Even"
+        );
+        assert_eq!(
+            Matcher::fizz()
+                .and(Matcher::prime("Prime"), "FizzPrime")
+                .describe(),
+            "both (every number divisible by 3) and (prime numbers) → FizzPrime"
+        );
+    }
+
+    #[test]
+    fn triangular_normal() {
+        let matcher = Matcher::triangular("Triangle");
+
+        for i in [1, 3, 6, 10, 15, 21, 5050].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [0, 2, 4, 11, 14, 5049].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        assert_eq!(matcher.number(), None);
+        assert_eq!(matcher.to_string(), "<triangular> => Triangle");
+    }
+
+    #[test]
+    fn triangular_equals_naive() {
+        let matcher = Matcher::triangular("Triangle");
+        let triangles: Vec<usize> = (1..200).map(|k| k * (k + 1) / 2).collect();
+
+        for i in 0..10_000 {
+            assert_eq!(
+                matcher.matches(i),
+                triangles.contains(&i),
+                "mismatch for {}",
+                i
+            );
+        }
+
+        // The largest triangular number, that fits into a `usize`.
+        let k = (2 * usize::MAX as u128).isqrt();
+        let largest = (k * (k + 1) / 2) as usize;
+        assert!(matcher.matches(largest));
+        assert!(!matcher.matches(largest + 1));
+        assert!(!matcher.matches(usize::MAX));
+    }
+
+    #[test]
+    fn triangular_composes() {
+        let mut matchers = Matcher::classic();
+        matchers.push(Matcher::triangular("Triangle"));
+
+        assert_eq!(crate::line(10, &matchers), "BuzzTriangle");
+        assert_eq!(crate::line(11, &matchers), "11");
+        assert_eq!(crate::line(15, &matchers), "FizzBuzzTriangle");
+        assert_eq!(crate::line(28, &matchers), "Triangle");
+    }
+
+    #[test]
+    fn power_of_two() {
+        let matcher = Matcher::power_of(2, "Power").unwrap();
+
+        for i in [1, 2, 4, 8, 16, 1024, 1 << 40].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [0, 3, 6, 12, 1023, (1 << 40) + 1].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        assert_eq!(matcher.number(), None);
+        assert_eq!(matcher.to_string(), "<power of 2> => Power");
+    }
+
+    #[test]
+    fn power_of_no_overflow() {
+        let matcher = Matcher::power_of(10, "Ten").unwrap();
+
+        assert!(matcher.matches(1000));
+        assert!(!matcher.matches(usize::MAX));
+
+        let matcher = Matcher::power_of(usize::MAX, "Max").unwrap();
+        assert!(matcher.matches(1));
+        assert!(matcher.matches(usize::MAX));
+        assert!(!matcher.matches(usize::MAX - 1));
+
+        let matcher = Matcher::power_of(2, "Power").unwrap();
+        assert!(matcher.matches(1 << (usize::BITS - 1)));
+        assert!(!matcher.matches(usize::MAX));
+    }
+
+    #[test]
+    fn power_of_invalid() {
+        assert!(matches!(
+            Matcher::power_of(0, "Zero"),
+            Err(MatcherError::BaseTooSmall { base: 0 })
+        ));
+        assert!(matches!(
+            Matcher::power_of(1, "One"),
+            Err(MatcherError::BaseTooSmall { base: 1 })
+        ));
+        assert!(matches!(
+            Matcher::power_of(2, ""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    fn and_divisor_prime() {
+        let matcher = Matcher::fizz().and(Matcher::prime("Prime"), "FizzPrime");
+
+        assert!(matcher.matches(3));
+        for i in (0..1000).filter(|i| *i != 3) {
+            assert!(!matcher.matches(i));
+        }
+        assert_eq!(matcher.word(), "FizzPrime");
+        assert_eq!(matcher.number(), None);
+        assert_eq!(
+            matcher.to_string(),
+            "(3 => Fizz and <prime> => Prime) => FizzPrime"
+        );
+    }
+
+    #[test]
+    fn or_divisor_prime() {
+        let matcher = Matcher::new(4, "Four")
+            .unwrap()
+            .or(Matcher::prime("Prime"), "Either");
+
+        for i in [2, 3, 4, 5, 8, 12].iter() {
+            assert!(matcher.matches(*i));
+        }
+        for i in [1, 6, 9, 10].iter() {
+            assert!(!matcher.matches(*i));
+        }
+        assert_eq!(crate::line(2, core::slice::from_ref(&matcher)), "Either");
+        assert_eq!(
+            matcher.to_string(),
+            "(4 => Four or <prime> => Prime) => Either"
+        );
+    }
+
+    #[test]
+    fn and_or_nested() {
+        let matcher = Matcher::fizz()
+            .or(Matcher::buzz(), "FizzOrBuzz")
+            .and(Matcher::prime("Prime"), "PrimeFizzOrBuzz");
+
+        assert!(matcher.matches(3));
+        assert!(matcher.matches(5));
+        assert!(!matcher.matches(7));
+        assert!(!matcher.matches(15));
+    }
+
+    #[test]
+    fn with_number_keeps_word() {
+        let matcher = Matcher::with_remainder(3, 1, "Off").unwrap();
+        let changed = matcher.with_number(5).unwrap();
+
+        assert_eq!(changed, Matcher::with_remainder(5, 1, "Off").unwrap());
+        assert_eq!(matcher, Matcher::with_remainder(3, 1, "Off").unwrap());
+
+        assert_eq!(
+            Matcher::exactly(42, "Answer").with_number(0).unwrap(),
+            Matcher::exactly(0, "Answer")
+        );
+        assert_eq!(
+            Matcher::negated(5, "NotBuzz")
+                .unwrap()
+                .with_number(3)
+                .unwrap(),
+            Matcher::negated(3, "NotBuzz").unwrap()
+        );
+    }
+
+    #[test]
+    fn with_number_invalid() {
+        let fizz = Matcher::fizz();
+
+        assert!(matches!(
+            fizz.with_number(0),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            Matcher::with_remainder(3, 2, "Two").unwrap().with_number(2),
+            Err(MatcherError::RemainderTooLarge {
+                number: 2,
+                remainder: 2
+            })
+        ));
+        assert!(matches!(
+            Matcher::prime("Prime").with_number(3),
+            Err(MatcherError::NoNumber)
+        ));
+        assert!(matches!(
+            Matcher::in_range(1, 5, "Low").unwrap().with_number(3),
+            Err(MatcherError::NoNumber)
+        ));
+    }
+
+    #[test]
+    fn with_word_keeps_rule() {
+        let matcher = Matcher::in_range(50, 59, "Fifties").unwrap();
+        let changed = matcher.with_word("Fünfziger").unwrap();
+
+        assert_eq!(changed, Matcher::in_range(50, 59, "Fünfziger").unwrap());
+        assert_eq!(matcher.word(), "Fifties");
+
+        assert!(matches!(
+            Matcher::fizz().with_word(""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn with_number_and_word_keep_color() {
+        let matcher = Matcher::fizz().with_color(Color::Green);
+
+        assert_eq!(matcher.with_number(4).unwrap().color(), Some(Color::Green));
+        assert_eq!(
+            matcher.with_word("Fuzz").unwrap().color(),
+            Some(Color::Green)
+        );
+    }
+
+    #[test]
+    fn divisor() {
+        assert_eq!(Matcher::new(3, "Fizz").unwrap().divisor(), Some(3));
+        assert_eq!(
+            Matcher::with_remainder(3, 1, "Off").unwrap().divisor(),
+            None
+        );
+        assert_eq!(Matcher::exactly(3, "Three").divisor(), None);
+        assert_eq!(Matcher::prime("Prime").divisor(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_format() {
+        let matcher = Matcher::new(3, "Fizz").unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&matcher).unwrap(),
+            r#"{"number":3,"word":"Fizz"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Matcher>(r#"{"number":3,"word":"Fizz"}"#).unwrap(),
+            matcher
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::with_remainder(3, 1, "Off").unwrap(),
+            Matcher::exactly(42, "Answer"),
+            Matcher::in_range(50, 59, "Fifties").unwrap(),
+            Matcher::prime("Prime"),
+            Matcher::perfect_square("Square"),
+            Matcher::contains_digit(3, "Three").unwrap(),
+            Matcher::all_of(&[3, 4], "Twelve").unwrap(),
+            Matcher::any_of(&[3, 7], "Flag").unwrap(),
+            Matcher::negated(5, "NotBuzz").unwrap(),
+            Matcher::digit_sum_divisible(5, "Five").unwrap(),
+            Matcher::palindrome("Palindrome"),
+            Matcher::triangular("Triangle"),
+            Matcher::power_of(2, "Power").unwrap(),
+            Matcher::fizz().and(Matcher::prime("Prime"), "FizzPrime"),
+            Matcher::fizz().or(Matcher::exactly(7, "Seven"), "FizzOrSeven"),
+        ];
+
+        let json = serde_json::to_string(&matchers).unwrap();
+        let parsed: Vec<Matcher> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, matchers);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_invalid() {
+        assert!(serde_json::from_str::<Matcher>(r#"{"number":0,"word":"Zero"}"#).is_err());
+        assert!(serde_json::from_str::<Matcher>(r#"{"word":"Fizz"}"#).is_err());
+        assert!(serde_json::from_str::<Matcher>(r#"{"number":3}"#).is_err());
+        assert!(serde_json::from_str::<Matcher>(
+            r#"{"kind":"range","low":9,"high":1,"word":"Range"}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Matcher>(r#"{"kind":"nope","word":"Nope"}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_from_fn() {
+        let matcher = Matcher::from_fn("Even", |n| n % 2 == 0);
+        assert!(serde_json::to_string(&matcher).is_err());
     }
 }
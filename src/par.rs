@@ -0,0 +1,74 @@
+use crate::{line, FizzBuzzError, Matcher};
+use rayon::prelude::*;
+
+/// Provides a configurable version of FizzBuzz, that returns the output and uses multiple threads.
+///
+/// This works the same way as `fizzbuzz_lines()`, but calculates the lines in parallel. The lines
+/// are still returned in ascending order. For small ranges, the overhead of distributing the work
+/// is bigger than the gain, so prefer `fizzbuzz_lines()` there.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_lines, fizzbuzz_lines_par, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(
+///     fizzbuzz_lines_par(1, 1000, &matchers).unwrap(),
+///     fizzbuzz_lines(1, 1000, &matchers).unwrap()
+/// );
+/// ```
+pub fn fizzbuzz_lines_par(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<Vec<String>, FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    Ok((from..=to)
+        .into_par_iter()
+        .map(|i| line(i, matchers))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fizzbuzz_lines;
+
+    #[test]
+    fn par_equals_serial() {
+        let mut matchers = Matcher::classic();
+        matchers.push(Matcher::prime("Prime"));
+
+        assert_eq!(
+            fizzbuzz_lines_par(1, 100_000, &matchers).unwrap(),
+            fizzbuzz_lines(1, 100_000, &matchers).unwrap()
+        );
+    }
+
+    #[test]
+    fn par_bounds() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(fizzbuzz_lines_par(3, 3, &matchers).unwrap(), vec!["Fizz"]);
+        assert_eq!(
+            fizzbuzz_lines_par(usize::MAX - 1, usize::MAX, &matchers).unwrap(),
+            fizzbuzz_lines(usize::MAX - 1, usize::MAX, &matchers).unwrap()
+        );
+    }
+
+    #[test]
+    fn par_from_bigger_than_to() {
+        assert!(matches!(
+            fizzbuzz_lines_par(2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{line, FizzBuzzError, Matcher};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+/// The longest period for which the output is precomputed.
+///
+/// Matchers with a longer combined period are evaluated for every number instead, to keep the
+/// memory usage of the precomputed pattern bounded.
+pub const MAX_PERIOD: usize = 1 << 16;
+
+/// A lazy version of FizzBuzz, that precomputes the repeating pattern of the output.
+///
+/// If all matchers are created by `Matcher::new()` or `Matcher::with_remainder()`, the output
+/// repeats every `n` numbers, where `n` is the least common multiple of their numbers. In that
+/// case, the words for one period are computed in advance, and only looked up afterwards. For
+/// other matchers, or if the period is longer than `MAX_PERIOD`, every number is checked against
+/// the matchers, just like `FizzBuzzIter` does.
+///
+/// Can be created with the `fizzbuzz_periodic()` function.
+///
+/// # Length
+/// The length of a range containing every possible `usize` value can't be represented as a
+/// `usize`. Calling `len()` on such an iterator will panic.
+#[derive(Clone, Debug)]
+pub struct PeriodicIter<'a> {
+    range: RangeInclusive<usize>,
+    matchers: &'a [Matcher],
+    /// The words for every number in one period, or `None` if the number itself is printed.
+    /// Empty, if the output isn't precomputed.
+    pattern: Vec<Option<String>>,
+}
+
+impl<'a> PeriodicIter<'a> {
+    /// Get the period of the precomputed pattern.
+    ///
+    /// Returns `None`, if the output isn't precomputed.
+    ///
+    /// # Example
+    /// ```
+    /// use extended_fizzbuzz::{fizzbuzz_periodic, Matcher};
+    ///
+    /// let matchers = Matcher::classic();
+    /// assert_eq!(fizzbuzz_periodic(1, 100, &matchers).unwrap().period(), Some(15));
+    ///
+    /// let matchers = vec![Matcher::prime("Prime")];
+    /// assert_eq!(fizzbuzz_periodic(1, 100, &matchers).unwrap().period(), None);
+    /// ```
+    pub fn period(&self) -> Option<usize> {
+        if self.pattern.is_empty() {
+            None
+        } else {
+            Some(self.pattern.len())
+        }
+    }
+
+    fn line(&self, number: usize) -> String {
+        if self.pattern.is_empty() {
+            return line(number, self.matchers);
+        }
+
+        match &self.pattern[number % self.pattern.len()] {
+            Some(words) => words.clone(),
+            None => number.to_string(),
+        }
+    }
+}
+
+impl<'a> Iterator for PeriodicIter<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.line(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for PeriodicIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.line(i))
+    }
+}
+
+impl<'a> ExactSizeIterator for PeriodicIter<'a> {}
+
+impl<'a> FusedIterator for PeriodicIter<'a> {}
+
+/// Provides a lazy version of FizzBuzz, that is optimized for huge ranges.
+///
+/// Yields the same lines as `fizzbuzz_iter()`. See `PeriodicIter` for when and how the output is
+/// precomputed.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`. The
+/// `matchers` are borrowed for as long as the iterator lives.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_periodic, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// let lines: Vec<_> = fizzbuzz_periodic(1_000_000_000, 2_000_000_000, &matchers)
+///     .unwrap()
+///     .take(6)
+///     .collect();
+///
+/// assert_eq!(
+///     lines,
+///     vec!["Buzz", "1000000001", "Fizz", "1000000003", "1000000004", "FizzBuzz"]
+/// );
+/// ```
+pub fn fizzbuzz_periodic(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<PeriodicIter<'_>, FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let pattern = match period(matchers) {
+        Some(period) => (0..period).map(|i| words(i, matchers)).collect(),
+        None => Vec::new(),
+    };
+
+    Ok(PeriodicIter {
+        range: from..=to,
+        matchers,
+        pattern,
+    })
+}
+
+/// Calculate the combined period of all matchers.
+///
+/// Returns `None`, if any matcher doesn't repeat, or if the period is longer than `MAX_PERIOD`.
+fn period(matchers: &[Matcher]) -> Option<usize> {
+    matchers.iter().try_fold(1, |period, m| {
+        let number = m.period()?;
+        let lcm = (period / gcd(period, number)).checked_mul(number)?;
+
+        if lcm <= MAX_PERIOD {
+            Some(lcm)
+        } else {
+            None
+        }
+    })
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Get the words of all matchers matching `number`, or `None` if the number itself is printed.
+fn words(number: usize, matchers: &[Matcher]) -> Option<String> {
+    let mut words = matchers
+        .iter()
+        .filter_map(|m| m.text_opt(number))
+        .peekable();
+
+    words.peek()?;
+    Some(words.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_same(from: usize, to: usize, matchers: &[Matcher]) {
+        let lines: Vec<_> = fizzbuzz_periodic(from, to, matchers).unwrap().collect();
+        let expected: Vec<_> = (from..=to).map(|i| line(i, matchers)).collect();
+
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn periodic_classic() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(
+            fizzbuzz_periodic(1, 1, &matchers).unwrap().period(),
+            Some(15)
+        );
+        assert_same(0, 15 * 7, &matchers);
+        assert_same(1_000_000_007, 1_000_000_007 + 15 * 5, &matchers);
+    }
+
+    #[test]
+    fn periodic_remainders() {
+        let matchers = vec![
+            Matcher::new(4, "Fizz").unwrap(),
+            Matcher::with_remainder(6, 1, "Buzz").unwrap(),
+            Matcher::with_remainder(9, 8, "Bazz").unwrap(),
+        ];
+
+        assert_eq!(
+            fizzbuzz_periodic(1, 1, &matchers).unwrap().period(),
+            Some(36)
+        );
+        assert_same(1, 36 * 4, &matchers);
+    }
+
+    #[test]
+    fn periodic_fallback() {
+        let mut matchers = Matcher::classic();
+        matchers.push(Matcher::prime("Prime"));
+        matchers.push(Matcher::in_range(50, 59, "Fifties").unwrap());
+
+        assert_eq!(fizzbuzz_periodic(1, 1, &matchers).unwrap().period(), None);
+        assert_same(1, 200, &matchers);
+    }
+
+    #[test]
+    fn periodic_too_long() {
+        let matchers = vec![
+            Matcher::new(MAX_PERIOD - 1, "Fizz").unwrap(),
+            Matcher::new(MAX_PERIOD - 3, "Buzz").unwrap(),
+        ];
+
+        assert_eq!(fizzbuzz_periodic(1, 1, &matchers).unwrap().period(), None);
+        assert_same(MAX_PERIOD * 2, MAX_PERIOD * 2 + 100, &matchers);
+
+        let matchers = vec![Matcher::new(usize::MAX, "Max").unwrap()];
+        assert_eq!(fizzbuzz_periodic(1, 1, &matchers).unwrap().period(), None);
+    }
+
+    #[test]
+    fn periodic_rev() {
+        let matchers = Matcher::classic();
+
+        let lines: Vec<_> = fizzbuzz_periodic(usize::MAX - 30, usize::MAX, &matchers)
+            .unwrap()
+            .rev()
+            .collect();
+        let expected: Vec<_> = (usize::MAX - 30..=usize::MAX)
+            .rev()
+            .map(|i| line(i, &matchers))
+            .collect();
+
+        assert_eq!(lines, expected);
+    }
+
+    #[test]
+    fn periodic_empty_words() {
+        let matchers = vec![Matcher::exactly(2, ""), Matcher::new(3, "Fizz").unwrap()];
+
+        assert_same(1, 30, &matchers);
+    }
+
+    #[test]
+    fn periodic_from_bigger_than_to() {
+        assert!(matches!(
+            fizzbuzz_periodic(2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+}
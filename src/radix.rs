@@ -0,0 +1,90 @@
+use crate::{line_fmt, Matcher};
+use alloc::format;
+use alloc::string::String;
+
+/// The bases numbers can be written in by `line_radix()`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Radix {
+    /// Base 2, prefixed by `0b`.
+    Bin,
+    /// Base 8, prefixed by `0o`.
+    Oct,
+    /// Base 10, without a prefix.
+    #[default]
+    Dec,
+    /// Base 16 with lowercase digits, prefixed by `0x`.
+    Hex,
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that writes numbers in a
+/// different base.
+///
+/// This works the same way as `line()`, but if no matcher matches, the number is written in the
+/// base set by `radix`. Words of matching matchers aren't changed.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_radix, Matcher, Radix};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(line_radix(10, &matchers, Radix::Hex), "Buzz");
+/// assert_eq!(line_radix(11, &matchers, Radix::Hex), "0xb");
+/// assert_eq!(line_radix(11, &matchers, Radix::Bin), "0b1011");
+/// ```
+pub fn line_radix(number: usize, matchers: &[Matcher], radix: Radix) -> String {
+    line_fmt(number, matchers, |n| match radix {
+        Radix::Bin => format!("{:#b}", n),
+        Radix::Oct => format!("{:#o}", n),
+        Radix::Dec => format!("{}", n),
+        Radix::Hex => format!("{:#x}", n),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line;
+
+    #[test]
+    fn radix_bin() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_radix(7, &matchers, Radix::Bin), "0b111");
+        assert_eq!(line_radix(15, &matchers, Radix::Bin), "FizzBuzz");
+    }
+
+    #[test]
+    fn radix_oct() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_radix(8, &matchers, Radix::Oct), "0o10");
+        assert_eq!(line_radix(9, &matchers, Radix::Oct), "Fizz");
+    }
+
+    #[test]
+    fn radix_dec() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(Radix::default(), Radix::Dec);
+        for i in 0..100 {
+            assert_eq!(line_radix(i, &matchers, Radix::Dec), line(i, &matchers));
+        }
+    }
+
+    #[test]
+    fn radix_hex() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_radix(11, &matchers, Radix::Hex), "0xb");
+        assert_eq!(line_radix(10, &matchers, Radix::Hex), "Buzz");
+        assert_eq!(line_radix(0, &[], Radix::Hex), "0x0");
+        assert_eq!(
+            line_radix(usize::MAX, &[], Radix::Hex),
+            format!("{:#x}", usize::MAX)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{fizzbuzz_iter, FizzBuzzError, FizzBuzzIter, Matcher};
+use alloc::string::String;
+use std::io::{self, Read};
+
+/// A reader producing the output of FizzBuzz.
+///
+/// Created by the `fizzbuzz_reader()` function. Lines are only generated when they are read, so
+/// the whole output is never kept in memory.
+#[derive(Clone, Debug)]
+pub struct FizzBuzzReader<'a> {
+    lines: FizzBuzzIter<'a>,
+    line: String,
+    pos: usize,
+}
+
+impl<'a> Read for FizzBuzzReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.pos == self.line.len() {
+            match self.lines.next() {
+                Some(line) => {
+                    self.line = line;
+                    self.line.push('\n');
+                    self.pos = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+
+        let rest = &self.line.as_bytes()[self.pos..];
+        let len = rest.len().min(buf.len());
+        buf[..len].copy_from_slice(&rest[..len]);
+        self.pos += len;
+
+        Ok(len)
+    }
+}
+
+/// Provides a configurable version of FizzBuzz, that can be read from.
+///
+/// The returned reader produces the same bytes `fizzbuzz_to()` would write. Every line is
+/// terminated by a `\n`.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_reader, Matcher};
+/// use std::io::Read;
+///
+/// let mut out = String::new();
+/// fizzbuzz_reader(1, 5, &Matcher::classic())
+///     .unwrap()
+///     .read_to_string(&mut out)
+///     .unwrap();
+///
+/// assert_eq!(out, "1\n2\nFizz\n4\nBuzz\n");
+/// assert!(fizzbuzz_reader(5, 1, &Matcher::classic()).is_err());
+/// ```
+pub fn fizzbuzz_reader(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<FizzBuzzReader<'_>, FizzBuzzError> {
+    Ok(FizzBuzzReader {
+        lines: fizzbuzz_iter(from, to, matchers)?,
+        line: String::new(),
+        pos: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fizzbuzz_lines;
+
+    #[test]
+    fn reader_equals_lines() {
+        let matchers = Matcher::classic();
+
+        let mut out = String::new();
+        fizzbuzz_reader(1, 100, &matchers)
+            .unwrap()
+            .read_to_string(&mut out)
+            .unwrap();
+
+        let mut expected = fizzbuzz_lines(1, 100, &matchers).unwrap().join("\n");
+        expected.push('\n');
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn reader_small_buffer() {
+        let matchers = Matcher::classic();
+        let mut reader = fizzbuzz_reader(14, 16, &matchers).unwrap();
+        let mut buf = [0; 3];
+        let mut out = Vec::new();
+
+        loop {
+            let len = reader.read(&mut buf).unwrap();
+            if len == 0 {
+                break;
+            }
+            assert!(len <= 3);
+            out.extend_from_slice(&buf[..len]);
+        }
+
+        assert_eq!(out, b"14\nFizzBuzz\n16\n");
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+        assert_eq!(reader.read(&mut []).unwrap(), 0);
+    }
+
+    #[test]
+    fn reader_from_bigger_than_to() {
+        assert!(matches!(
+            fizzbuzz_reader(2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{push_number, Matcher};
+use alloc::string::String;
+
+/// A rule deciding wether a number should be substituted, and by what.
+///
+/// This is implemented for `Matcher`, but can also be implemented for other types. Use
+/// `line_rules()` to mix them.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::Rule;
+///
+/// struct Even;
+///
+/// impl Rule for Even {
+///     fn matches(&self, n: usize) -> bool {
+///         n % 2 == 0
+///     }
+///
+///     fn word(&self) -> &str {
+///         "Even"
+///     }
+/// }
+///
+/// assert!(Even.matches(4));
+/// assert_eq!(Even.word(), "Even");
+/// ```
+pub trait Rule {
+    /// Check wether `n` should be substituted.
+    fn matches(&self, n: usize) -> bool;
+
+    /// Get the text matching numbers are substituted by.
+    fn word(&self) -> &str;
+}
+
+impl Rule for Matcher {
+    fn matches(&self, n: usize) -> bool {
+        Matcher::matches(self, n)
+    }
+
+    fn word(&self) -> &str {
+        Matcher::word(self)
+    }
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that uses arbitrary rules.
+///
+/// This works the same way as `line()`, but takes any type implementing `Rule`. The words of all
+/// matching rules are concatenated in the order of `rules`.
+///
+/// # Parameters
+/// The `number` parameter works the same way as for `line()`.
+///
+/// With `rules` you can provide the rules to apply. They don't need to be of the same type.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_rules, Matcher, Rule};
+///
+/// struct Seven;
+///
+/// impl Rule for Seven {
+///     fn matches(&self, n: usize) -> bool {
+///         n.to_string().contains('7')
+///     }
+///
+///     fn word(&self) -> &str {
+///         "Bang"
+///     }
+/// }
+///
+/// let fizz = Matcher::fizz();
+/// let rules: [&dyn Rule; 2] = [&fizz, &Seven];
+///
+/// assert_eq!(line_rules(9, &rules), "Fizz");
+/// assert_eq!(line_rules(17, &rules), "Bang");
+/// assert_eq!(line_rules(27, &rules), "FizzBang");
+/// assert_eq!(line_rules(8, &rules), "8");
+/// ```
+pub fn line_rules(number: usize, rules: &[&dyn Rule]) -> String {
+    let mut out = String::new();
+    let mut matched = false;
+
+    for rule in rules.iter().filter(|r| r.matches(number)) {
+        out.push_str(rule.word());
+        matched = true;
+    }
+
+    if !matched {
+        push_number(number, &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line;
+
+    /// Substitutes numbers with an odd count of set bits.
+    struct OddBits;
+
+    impl Rule for OddBits {
+        fn matches(&self, n: usize) -> bool {
+            n.count_ones() % 2 == 1
+        }
+
+        fn word(&self) -> &str {
+            "Odd"
+        }
+    }
+
+    #[test]
+    fn rules_mixed() {
+        let fizz = Matcher::fizz();
+        let rules: [&dyn Rule; 2] = [&fizz, &OddBits];
+
+        assert_eq!(line_rules(1, &rules), "Odd");
+        assert_eq!(line_rules(3, &rules), "Fizz");
+        assert_eq!(line_rules(5, &rules), "5");
+        assert_eq!(line_rules(7, &rules), "Odd");
+        assert_eq!(line_rules(21, &rules), "FizzOdd");
+
+        let rules: [&dyn Rule; 2] = [&OddBits, &fizz];
+        assert_eq!(line_rules(21, &rules), "OddFizz");
+    }
+
+    #[test]
+    fn rules_equal_line() {
+        let matchers = Matcher::classic();
+        let rules: Vec<&dyn Rule> = matchers.iter().map(|m| m as &dyn Rule).collect();
+
+        for i in 0..100 {
+            assert_eq!(line_rules(i, &rules), line(i, &matchers));
+        }
+        assert_eq!(line_rules(7, &[]), "7");
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{line_into, FizzBuzzError, Matcher};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Find the numbers in a range, for which FizzBuzz produces a specific output.
+///
+/// Every number `n` in the range for which `line(n, matchers)` equals `word` is returned, in
+/// ascending order. This works for substituted words as well as for numbers that aren't
+/// substituted.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{numbers_for, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(numbers_for("FizzBuzz", 1, 30, &matchers).unwrap(), vec![15, 30]);
+/// assert_eq!(numbers_for("7", 1, 30, &matchers).unwrap(), vec![7]);
+/// assert!(numbers_for("6", 1, 30, &matchers).unwrap().is_empty());
+/// ```
+pub fn numbers_for(
+    word: &str,
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<Vec<usize>, FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let mut buf = String::new();
+
+    Ok((from..=to)
+        .filter(|i| {
+            line_into(*i, matchers, &mut buf);
+            buf == word
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_for_words() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(
+            numbers_for("Fizz", 1, 30, &matchers).unwrap(),
+            vec![3, 6, 9, 12, 18, 21, 24, 27]
+        );
+        assert_eq!(
+            numbers_for("Buzz", 1, 30, &matchers).unwrap(),
+            vec![5, 10, 20, 25]
+        );
+        assert_eq!(
+            numbers_for("FizzBuzz", 1, 30, &matchers).unwrap(),
+            vec![15, 30]
+        );
+        assert!(numbers_for("BuzzFizz", 1, 30, &matchers)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn numbers_for_numbers() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(numbers_for("7", 1, 30, &matchers).unwrap(), vec![7]);
+        assert!(numbers_for("7", 8, 30, &matchers).unwrap().is_empty());
+        assert!(numbers_for("9", 1, 30, &matchers).unwrap().is_empty());
+        assert!(numbers_for("07", 1, 30, &matchers).unwrap().is_empty());
+        assert_eq!(numbers_for("0", 0, 0, &[]).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn numbers_for_from_bigger_than_to() {
+        assert!(matches!(
+            numbers_for("Fizz", 2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+}
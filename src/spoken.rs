@@ -0,0 +1,165 @@
+use crate::{line_fmt, Matcher};
+use alloc::string::String;
+
+/// The names of the numbers below 20.
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// The names of the multiples of 10, starting at 0.
+const TENS: [&str; 10] = [
+    "", "ten", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The names of the powers of 1000, from the largest down.
+const SCALES: [(u64, &str); 6] = [
+    (1_000_000_000_000_000_000, "quintillion"),
+    (1_000_000_000_000_000, "quadrillion"),
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+/// Provides a configurable version of FizzBuzz for a single number, that spells out numbers.
+///
+/// This works the same way as `line()`, but if no matcher matches, the number is spelled out in
+/// English words. Words of matching matchers aren't changed.
+///
+/// # Parameters
+/// The `number` and `matchers` parameters work the same way as for `line()`.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{line_spoken, Matcher};
+///
+/// let matchers = Matcher::classic();
+///
+/// assert_eq!(line_spoken(7, &matchers), "seven");
+/// assert_eq!(line_spoken(15, &matchers), "FizzBuzz");
+/// assert_eq!(line_spoken(121, &matchers), "one hundred twenty-one");
+/// ```
+pub fn line_spoken(number: usize, matchers: &[Matcher]) -> String {
+    line_fmt(number, matchers, spell)
+}
+
+/// Spell out `number` in English words.
+fn spell(number: usize) -> String {
+    if number == 0 {
+        return ONES[0].into();
+    }
+
+    let mut out = String::new();
+    let mut rest = number as u64;
+
+    for (scale, name) in SCALES.iter() {
+        if rest >= *scale {
+            push_hundreds((rest / scale) as usize, &mut out);
+            out.push(' ');
+            out.push_str(name);
+            rest %= scale;
+        }
+    }
+    if rest > 0 {
+        push_hundreds(rest as usize, &mut out);
+    }
+
+    out
+}
+
+/// Append the words for `number`, which has to be between 1 and 999, to `out`.
+///
+/// If `out` isn't empty, a space is added first.
+fn push_hundreds(mut number: usize, out: &mut String) {
+    if !out.is_empty() {
+        out.push(' ');
+    }
+
+    if number >= 100 {
+        out.push_str(ONES[number / 100]);
+        out.push_str(" hundred");
+        number %= 100;
+
+        if number > 0 {
+            out.push(' ');
+        }
+    }
+
+    if number >= 20 {
+        out.push_str(TENS[number / 10]);
+        if number % 10 != 0 {
+            out.push('-');
+            out.push_str(ONES[number % 10]);
+        }
+    } else if number > 0 {
+        out.push_str(ONES[number]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line;
+
+    #[test]
+    fn spoken_unmatched() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_spoken(7, &matchers), "seven");
+        assert_eq!(line_spoken(13, &matchers), "thirteen");
+        assert_eq!(line_spoken(21, &[]), "twenty-one");
+        assert_eq!(line_spoken(0, &[]), "zero");
+    }
+
+    #[test]
+    fn spoken_matched() {
+        let matchers = Matcher::classic();
+
+        assert_eq!(line_spoken(15, &matchers), "FizzBuzz");
+        for i in (0..1000).filter(|i| i % 3 == 0 || i % 5 == 0) {
+            assert_eq!(line_spoken(i, &matchers), line(i, &matchers));
+        }
+    }
+
+    #[test]
+    fn spell_numbers() {
+        assert_eq!(spell(20), "twenty");
+        assert_eq!(spell(99), "ninety-nine");
+        assert_eq!(spell(100), "one hundred");
+        assert_eq!(spell(101), "one hundred one");
+        assert_eq!(spell(340), "three hundred forty");
+        assert_eq!(spell(1000), "one thousand");
+        assert_eq!(spell(1010), "one thousand ten");
+        assert_eq!(spell(9999), "nine thousand nine hundred ninety-nine");
+        assert_eq!(spell(2_000_005), "two million five");
+        assert_eq!(spell(1_000_200_000), "one billion two hundred thousand");
+    }
+
+    #[test]
+    fn spell_max() {
+        let spoken = spell(usize::MAX);
+
+        assert!(!spoken.is_empty());
+        assert!(!spoken.contains("  "));
+        assert!(!spoken.ends_with(' '));
+    }
+}
@@ -0,0 +1,364 @@
+use crate::Matcher;
+#[cfg(feature = "std")]
+use crate::{push_number, with_stdout, FizzBuzzError};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// A rule, that can remember earlier numbers to decide how to substitute the current one.
+///
+/// Other than `Matcher` and `Rule`, a stateful rule is called with every number of a range in
+/// order, and may change its state in between. Use `fizzbuzz_stateful()` to run stateful rules.
+///
+/// This is implemented for `Matcher`, which simply ignores the earlier numbers.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::StatefulRule;
+///
+/// /// Substitutes every second number it is called with.
+/// struct EverySecond(bool);
+///
+/// impl StatefulRule for EverySecond {
+///     fn step(&mut self, _: usize) -> Option<String> {
+///         self.0 = !self.0;
+///         if self.0 { None } else { Some("Second".to_string()) }
+///     }
+/// }
+///
+/// let mut rule = EverySecond(false);
+/// assert_eq!(rule.step(10), None);
+/// assert_eq!(rule.step(20), Some("Second".to_string()));
+/// ```
+pub trait StatefulRule {
+    /// Advance the rule to `n`, and get the text `n` should be substituted by.
+    ///
+    /// Returns `None`, if `n` shouldn't be substituted by this rule.
+    fn step(&mut self, n: usize) -> Option<String>;
+}
+
+impl StatefulRule for Matcher {
+    fn step(&mut self, n: usize) -> Option<String> {
+        self.text_opt(n).map(ToOwned::to_owned)
+    }
+}
+
+/// A stateful rule, that stops substituting numbers after a number of hits.
+///
+/// Created by `Matcher::bounded()`, or `Bounded::new()` for other kinds of matchers.
+///
+/// The hits are counted across all calls of `step()`, so a rule used for multiple runs has to be
+/// reset in between.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{Bounded, Matcher, StatefulRule};
+///
+/// let mut prime = Bounded::new(Matcher::prime("Prime"), 2);
+///
+/// let words: Vec<_> = (1..=7).filter_map(|n| prime.step(n)).collect();
+/// assert_eq!(words, vec!["Prime", "Prime"]);
+///
+/// prime.reset();
+/// assert_eq!(prime.step(7), Some("Prime".to_string()));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Bounded {
+    matcher: Matcher,
+    max_hits: usize,
+    hits: usize,
+}
+
+impl Bounded {
+    /// Create a new rule, that substitutes the numbers `matcher` matches, until it has
+    /// substituted `max_hits` of them.
+    pub fn new(matcher: Matcher, max_hits: usize) -> Self {
+        Bounded {
+            matcher,
+            max_hits,
+            hits: 0,
+        }
+    }
+
+    /// Get the matcher deciding wether a number is a hit.
+    pub fn matcher(&self) -> &Matcher {
+        &self.matcher
+    }
+
+    /// Get the number of hits after which no numbers are substituted anymore.
+    pub fn max_hits(&self) -> usize {
+        self.max_hits
+    }
+
+    /// Get the number of numbers substituted so far.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Forget all hits, so that the rule substitutes numbers again.
+    pub fn reset(&mut self) {
+        self.hits = 0;
+    }
+}
+
+impl StatefulRule for Bounded {
+    fn step(&mut self, n: usize) -> Option<String> {
+        if self.hits == self.max_hits || !self.matcher.matches(n) {
+            return None;
+        }
+
+        self.hits += 1;
+        Some(self.matcher.word().to_owned())
+    }
+}
+
+/// Provides a configurable version of FizzBuzz, that uses stateful rules.
+///
+/// The output is written to stdout. Use `fizzbuzz_stateful_to()` to write it somewhere else.
+///
+/// This works the same way as `fizzbuzz()`, but uses rules that can keep track of earlier
+/// numbers. Every rule is advanced with every number in the range, in ascending order, even if an
+/// earlier rule already substituted it. The texts of all rules are concatenated in the order of
+/// `rules`.
+///
+/// # Parameters
+/// The `from` and `to` parameters work the same way as for `fizzbuzz()`.
+///
+/// With `rules` you can provide the rules to apply. They don't need to be of the same type.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. No rule is advanced in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to stdout fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_stateful, Matcher, StatefulRule};
+///
+/// let mut fizz = Matcher::fizz();
+/// let mut buzz = Matcher::buzz();
+///
+/// assert!(fizzbuzz_stateful(1, 15, &mut [&mut fizz, &mut buzz]).is_ok());
+/// assert!(fizzbuzz_stateful(15, 1, &mut [&mut fizz, &mut buzz]).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_stateful(
+    from: usize,
+    to: usize,
+    rules: &mut [&mut dyn StatefulRule],
+) -> Result<(), FizzBuzzError> {
+    with_stdout(|out| fizzbuzz_stateful_to(out, from, to, rules))
+}
+
+/// Provides a configurable version of FizzBuzz, that uses stateful rules and writes to an
+/// arbitrary writer.
+///
+/// Every line is terminated by a `\n`.
+///
+/// # Parameters
+/// With `writer` you can provide the destination of the output.
+///
+/// The `from`, `to` and `rules` parameters work the same way as for `fizzbuzz_stateful()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value. Nothing is written in that case.
+/// - Returns `FizzBuzzError::Io`, if writing to the `writer` fails.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_stateful_to, StatefulRule};
+///
+/// /// Substitutes every number with the sum of all numbers so far.
+/// struct Sum(usize);
+///
+/// impl StatefulRule for Sum {
+///     fn step(&mut self, n: usize) -> Option<String> {
+///         self.0 += n;
+///         Some(self.0.to_string())
+///     }
+/// }
+///
+/// let mut out = Vec::new();
+/// fizzbuzz_stateful_to(&mut out, 1, 4, &mut [&mut Sum(0)]).unwrap();
+///
+/// assert_eq!(out, b"1\n3\n6\n10\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_stateful_to<W: Write>(
+    writer: &mut W,
+    from: usize,
+    to: usize,
+    rules: &mut [&mut dyn StatefulRule],
+) -> Result<(), FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let mut buf = String::new();
+
+    for i in from..=to {
+        buf.clear();
+        let mut matched = false;
+
+        for rule in rules.iter_mut() {
+            if let Some(text) = rule.step(i) {
+                buf.push_str(&text);
+                matched = true;
+            }
+        }
+        if !matched {
+            push_number(i, &mut buf);
+        }
+
+        buf.push('\n');
+        writer
+            .write_all(buf.as_bytes())
+            .map_err(FizzBuzzError::io_at(i))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MatcherError;
+    use alloc::format;
+    #[cfg(feature = "std")]
+    use alloc::string::ToString;
+
+    /// Substitutes multiples of 5 by `Buzz`, and every number after them by `Buzz+` and the
+    /// distance to the last multiple.
+    #[derive(Default)]
+    struct SinceBuzz {
+        since: Option<usize>,
+    }
+
+    impl StatefulRule for SinceBuzz {
+        fn step(&mut self, n: usize) -> Option<String> {
+            if n % 5 == 0 {
+                self.since = Some(0);
+                return Some("Buzz".into());
+            }
+
+            let since = self.since.as_mut()?;
+            *since += 1;
+            Some(format!("Buzz+{}", since))
+        }
+    }
+
+    #[test]
+    fn stateful_step() {
+        let mut rule = SinceBuzz::default();
+
+        let steps: Vec<_> = (1..=12).map(|n| rule.step(n)).collect();
+        assert_eq!(steps[..4], [None, None, None, None]);
+        assert_eq!(steps[4].as_deref(), Some("Buzz"));
+        assert_eq!(steps[8].as_deref(), Some("Buzz+4"));
+        assert_eq!(steps[11].as_deref(), Some("Buzz+2"));
+    }
+
+    #[test]
+    fn stateful_matcher() {
+        let mut fizz = Matcher::fizz();
+
+        assert_eq!(fizz.step(3), Some("Fizz".into()));
+        assert_eq!(fizz.step(4), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stateful_since_buzz() {
+        let mut out = Vec::new();
+        fizzbuzz_stateful_to(&mut out, 1, 12, &mut [&mut SinceBuzz::default()]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "1\n2\n3\n4\nBuzz\nBuzz+1\nBuzz+2\nBuzz+3\nBuzz+4\nBuzz\nBuzz+1\nBuzz+2\n"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stateful_mixed() {
+        let mut fizz = Matcher::fizz();
+        let mut since = SinceBuzz::default();
+
+        let mut out = Vec::new();
+        fizzbuzz_stateful_to(&mut out, 4, 7, &mut [&mut fizz, &mut since]).unwrap();
+
+        // The second rule is advanced for 6, even though the first one substituted it.
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "4\nBuzz\nFizzBuzz+1\nBuzz+2\n"
+        );
+    }
+
+    #[test]
+    fn bounded_step() {
+        let mut buzz = Matcher::bounded(5, 3, "Buzz").unwrap();
+
+        let hits: Vec<_> = (1..=30).filter(|n| buzz.step(*n).is_some()).collect();
+        assert_eq!(hits, vec![5, 10, 15]);
+        assert_eq!(buzz.hits(), 3);
+
+        buzz.reset();
+        assert_eq!(buzz.step(30), Some("Buzz".into()));
+        assert_eq!(buzz.hits(), 1);
+
+        let mut never = Matcher::bounded(5, 0, "Never").unwrap();
+        assert!((1..=30).all(|n| never.step(n).is_none()));
+    }
+
+    #[test]
+    fn bounded_invalid() {
+        assert!(matches!(
+            Matcher::bounded(0, 3, "Zero"),
+            Err(MatcherError::NumberIsZero)
+        ));
+        assert!(matches!(
+            Matcher::bounded(5, 3, ""),
+            Err(MatcherError::EmptyWord)
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn bounded_stateful_run() {
+        let mut fizz = Matcher::fizz();
+        let mut buzz = Matcher::bounded(5, 3, "Buzz").unwrap();
+
+        let mut out = Vec::new();
+        fizzbuzz_stateful_to(&mut out, 1, 30, &mut [&mut fizz, &mut buzz]).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+
+        for (i, line) in (1usize..=30).zip(lines.iter()) {
+            let buzz = i == 5 || i == 10 || i == 15;
+            let expected = match (i % 3 == 0, buzz) {
+                (true, true) => "FizzBuzz".to_owned(),
+                (true, false) => "Fizz".to_owned(),
+                (false, true) => "Buzz".to_owned(),
+                (false, false) => i.to_string(),
+            };
+            assert_eq!(*line, expected);
+        }
+        assert_eq!(lines.len(), 30);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn stateful_from_bigger_than_to() {
+        let mut since = SinceBuzz::default();
+        let mut out = Vec::new();
+
+        assert!(matches!(
+            fizzbuzz_stateful_to(&mut out, 5, 1, &mut [&mut since]),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 5, to: 1 })
+        ));
+        assert!(out.is_empty());
+        assert_eq!(since.since, None);
+    }
+}
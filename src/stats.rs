@@ -0,0 +1,194 @@
+use crate::{line_into, FizzBuzzError, Matcher};
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Statistics about the output of FizzBuzz over a range.
+///
+/// Created by the `stats()` function.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FizzBuzzStats {
+    /// How many numbers each matcher matched, in the order of the matchers. A number that is
+    /// matched by multiple matchers is counted for each of them.
+    pub per_matcher: Vec<usize>,
+    /// How many numbers no matcher matched.
+    pub unmatched: usize,
+    /// How many numbers were checked.
+    pub total: usize,
+}
+
+/// Calculate statistics about the output of FizzBuzz over a range.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{stats, Matcher};
+///
+/// let stats = stats(1, 15, &Matcher::classic()).unwrap();
+///
+/// assert_eq!(stats.per_matcher, vec![5, 3]);
+/// assert_eq!(stats.unmatched, 8);
+/// assert_eq!(stats.total, 15);
+/// ```
+pub fn stats(from: usize, to: usize, matchers: &[Matcher]) -> Result<FizzBuzzStats, FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let mut stats = FizzBuzzStats {
+        per_matcher: vec![0; matchers.len()],
+        ..Default::default()
+    };
+
+    for i in from..=to {
+        let mut matched = false;
+
+        for (count, m) in stats.per_matcher.iter_mut().zip(matchers.iter()) {
+            if m.matches(i) {
+                *count += 1;
+                matched = true;
+            }
+        }
+
+        if !matched {
+            stats.unmatched += 1;
+        }
+        stats.total += 1;
+    }
+
+    Ok(stats)
+}
+
+/// Count how often each line occurs in the output of FizzBuzz over a range.
+///
+/// # Parameters
+/// The `from`, `to` and `matchers` parameters work the same way as for `fizzbuzz()`.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::FromBiggerThanTo`, if the `from` parameters value is bigger than the
+///   `to` parameters value.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{unique_outputs, Matcher};
+///
+/// let outputs = unique_outputs(1, 15, &Matcher::classic()).unwrap();
+///
+/// assert_eq!(outputs.len(), 11);
+/// assert_eq!(outputs["Fizz"], 4);
+/// assert_eq!(outputs["FizzBuzz"], 1);
+/// assert_eq!(outputs["7"], 1);
+/// ```
+pub fn unique_outputs(
+    from: usize,
+    to: usize,
+    matchers: &[Matcher],
+) -> Result<BTreeMap<String, usize>, FizzBuzzError> {
+    if from > to {
+        return Err(FizzBuzzError::FromBiggerThanTo { from, to });
+    }
+
+    let mut outputs = BTreeMap::new();
+    let mut buf = String::new();
+
+    for i in from..=to {
+        line_into(i, matchers, &mut buf);
+
+        // Only allocate a key for lines that weren't seen before.
+        match outputs.get_mut(buf.as_str()) {
+            Some(count) => *count += 1,
+            None => {
+                outputs.insert(buf.clone(), 1);
+            }
+        }
+    }
+
+    Ok(outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_classic() {
+        let stats = stats(1, 15, &Matcher::classic()).unwrap();
+
+        assert_eq!(
+            stats,
+            FizzBuzzStats {
+                per_matcher: vec![5, 3],
+                unmatched: 8,
+                total: 15,
+            }
+        );
+    }
+
+    #[test]
+    fn stats_hundred() {
+        let mut matchers = Matcher::classic();
+        matchers.push(Matcher::exactly(42, "Answer"));
+
+        let stats = stats(1, 100, &matchers).unwrap();
+
+        assert_eq!(stats.per_matcher, vec![33, 20, 1]);
+        // 47 numbers are divisible by 3 or 5, 42 is one of them.
+        assert_eq!(stats.unmatched, 53);
+        assert_eq!(stats.total, 100);
+    }
+
+    #[test]
+    fn stats_no_matchers() {
+        let stats = stats(10, 19, &[]).unwrap();
+
+        assert!(stats.per_matcher.is_empty());
+        assert_eq!(stats.unmatched, 10);
+        assert_eq!(stats.total, 10);
+    }
+
+    #[test]
+    fn unique_outputs_classic() {
+        let outputs = unique_outputs(1, 15, &Matcher::classic()).unwrap();
+
+        let mut expected = BTreeMap::new();
+        expected.insert("Fizz".to_string(), 4);
+        expected.insert("Buzz".to_string(), 2);
+        expected.insert("FizzBuzz".to_string(), 1);
+        for i in [1, 2, 4, 7, 8, 11, 13, 14].iter() {
+            expected.insert(i.to_string(), 1);
+        }
+
+        assert_eq!(outputs, expected);
+    }
+
+    #[test]
+    fn unique_outputs_single() {
+        let outputs = unique_outputs(7, 7, &Matcher::classic()).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs["7"], 1);
+    }
+
+    #[test]
+    fn unique_outputs_from_bigger_than_to() {
+        assert!(matches!(
+            unique_outputs(2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+
+    #[test]
+    fn stats_from_bigger_than_to() {
+        assert!(matches!(
+            stats(2, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 2, to: 1 })
+        ));
+    }
+}
@@ -0,0 +1,43 @@
+use std::io::{self, Write};
+
+/// A writer that fails once it is written to for the `fail_at`th time, or once it is asked to
+/// write a buffer containing the `fail_on` byte.
+pub(crate) struct FailingWriter {
+    pub(crate) writes: usize,
+    fail_at: usize,
+    fail_on: Option<u8>,
+}
+
+impl FailingWriter {
+    /// Create a writer failing on the `fail_at`th write.
+    pub(crate) fn new(fail_at: usize) -> Self {
+        FailingWriter {
+            writes: 0,
+            fail_at,
+            fail_on: None,
+        }
+    }
+
+    /// Create a writer failing on every write containing `fail_on`.
+    pub(crate) fn on_byte(fail_on: u8) -> Self {
+        FailingWriter {
+            writes: 0,
+            fail_at: usize::MAX,
+            fail_on: Some(fail_on),
+        }
+    }
+}
+
+impl Write for FailingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writes += 1;
+        if self.writes >= self.fail_at || self.fail_on.is_some_and(|b| buf.contains(&b)) {
+            return Err(io::Error::new(io::ErrorKind::BrokenPipe, "failing writer"));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
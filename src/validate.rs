@@ -0,0 +1,219 @@
+#[cfg(feature = "std")]
+use crate::fizzbuzz;
+use crate::{FizzBuzzError, Matcher};
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// Check a set of matchers for likely mistakes.
+///
+/// Nothing is wrong with the matchers from a technical point of view, but they most likely don't
+/// do what was intended. Use `fizzbuzz_checked()` to run this check before producing output.
+///
+/// # Errors
+/// - Returns `FizzBuzzError::DivisorIsOne`, if a matcher created by `Matcher::new()` uses 1 as
+///   its number. It would substitute every number.
+/// - Returns `FizzBuzzError::DuplicateDivisor`, if two matchers created by `Matcher::new()` use
+///   the same number. The output would contain both words, which is usually a bug.
+///
+/// The matchers are checked in order, and the first problem found is returned.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{validate_matchers, Matcher};
+///
+/// assert!(validate_matchers(&Matcher::classic()).is_ok());
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(3, "Buzz").unwrap(),
+/// ];
+/// assert!(validate_matchers(&matchers).is_err());
+/// ```
+pub fn validate_matchers(matchers: &[Matcher]) -> Result<(), FizzBuzzError> {
+    let mut divisors = BTreeSet::new();
+
+    for m in matchers {
+        let number = match m.divisor() {
+            Some(number) => number,
+            None => continue,
+        };
+
+        if number == 1 {
+            return Err(FizzBuzzError::DivisorIsOne {
+                word: m.word().to_owned(),
+            });
+        }
+        if !divisors.insert(number) {
+            return Err(FizzBuzzError::DuplicateDivisor { number });
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove duplicate matchers.
+///
+/// Only identical matchers are removed, the first occurrence of each is kept in place. Other than
+/// `validate_matchers()`, matchers using the same divisor with different words are kept.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{dedupe, Matcher};
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(5, "Buzz").unwrap(),
+///     Matcher::new(3, "Fizz").unwrap(),
+/// ];
+///
+/// assert_eq!(dedupe(matchers), Matcher::classic());
+/// ```
+pub fn dedupe(matchers: Vec<Matcher>) -> Vec<Matcher> {
+    let mut seen = BTreeSet::new();
+
+    matchers
+        .into_iter()
+        .filter(|m| seen.insert(m.clone()))
+        .collect()
+}
+
+/// Provides a configurable version of FizzBuzz, that checks the matchers first.
+///
+/// This works the same way as `fizzbuzz()`, but runs `validate_matchers()` before producing any
+/// output.
+///
+/// # Errors
+/// - Returns all errors `validate_matchers()` returns. Nothing is printed in that case.
+/// - Returns all errors `fizzbuzz()` returns.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::{fizzbuzz_checked, Matcher};
+///
+/// assert!(fizzbuzz_checked(1, 15, &Matcher::classic()).is_ok());
+///
+/// let matchers = vec![
+///     Matcher::new(3, "Fizz").unwrap(),
+///     Matcher::new(3, "Buzz").unwrap(),
+/// ];
+/// assert!(fizzbuzz_checked(1, 15, &matchers).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn fizzbuzz_checked(from: usize, to: usize, matchers: &[Matcher]) -> Result<(), FizzBuzzError> {
+    validate_matchers(matchers)?;
+    fizzbuzz(from, to, matchers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_clean() {
+        assert!(validate_matchers(&Matcher::classic()).is_ok());
+        assert!(validate_matchers(&[]).is_ok());
+
+        // Only plain divisors are compared, other rules may use the same number.
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::with_remainder(3, 1, "Off").unwrap(),
+            Matcher::exactly(3, "Three"),
+        ];
+        assert!(validate_matchers(&matchers).is_ok());
+    }
+
+    #[test]
+    fn validate_duplicate() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+            Matcher::new(3, "Fuzz").unwrap(),
+        ];
+
+        assert!(matches!(
+            validate_matchers(&matchers),
+            Err(FizzBuzzError::DuplicateDivisor { number: 3 })
+        ));
+    }
+
+    #[test]
+    fn validate_divisor_is_one() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(1, "Everything").unwrap(),
+        ];
+
+        match validate_matchers(&matchers) {
+            Err(FizzBuzzError::DivisorIsOne { word }) => assert_eq!(word, "Everything"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        // Other rules using 1 are fine.
+        let matchers = vec![
+            Matcher::exactly(1, "One"),
+            Matcher::with_remainder(2, 1, "Odd").unwrap(),
+        ];
+        assert!(validate_matchers(&matchers).is_ok());
+    }
+
+    #[test]
+    fn dedupe_identical() {
+        let matchers = vec![
+            Matcher::new(5, "Buzz").unwrap(),
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+            Matcher::exactly(7, "Seven"),
+            Matcher::exactly(7, "Seven"),
+        ];
+
+        assert_eq!(
+            dedupe(matchers),
+            vec![
+                Matcher::new(5, "Buzz").unwrap(),
+                Matcher::new(3, "Fizz").unwrap(),
+                Matcher::exactly(7, "Seven"),
+            ]
+        );
+        assert!(dedupe(vec![]).is_empty());
+    }
+
+    #[test]
+    fn dedupe_same_divisor() {
+        let matchers = vec![
+            Matcher::new(3, "Fizz").unwrap(),
+            Matcher::new(3, "Fuzz").unwrap(),
+            Matcher::exactly(3, "Fizz"),
+        ];
+
+        assert_eq!(dedupe(matchers.clone()), matchers);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_checked_divisor_is_one() {
+        assert!(matches!(
+            fizzbuzz_checked(1, 15, &[Matcher::new(1, "X").unwrap()]),
+            Err(FizzBuzzError::DivisorIsOne { .. })
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fizzbuzz_checked_duplicate() {
+        let matchers = vec![
+            Matcher::new(5, "Buzz").unwrap(),
+            Matcher::new(5, "Buzz").unwrap(),
+        ];
+
+        assert!(matches!(
+            fizzbuzz_checked(1, 15, &matchers),
+            Err(FizzBuzzError::DuplicateDivisor { number: 5 })
+        ));
+        assert!(matches!(
+            fizzbuzz_checked(15, 1, &Matcher::classic()),
+            Err(FizzBuzzError::FromBiggerThanTo { from: 15, to: 1 })
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{line, Matcher};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+/// Provides a configurable version of FizzBuzz for a single number, that reads the matchers from
+/// JSON.
+///
+/// This works the same way as `line()`, but the matchers are deserialized from `matchers` first.
+/// It is the part of `line_js()` that doesn't depend on JavaScript.
+///
+/// # Parameters
+/// The `number` parameter works the same way as for `line()`.
+///
+/// With `matchers` you can provide a JSON array of matchers, in the format their `Deserialize`
+/// implementation expects.
+///
+/// # Errors
+/// Returns all errors that occur while deserializing the matchers.
+///
+/// # Example
+/// ```
+/// use extended_fizzbuzz::line_json;
+///
+/// let matchers = r#"[{"number":3,"word":"Fizz"},{"number":5,"word":"Buzz"}]"#;
+///
+/// assert_eq!(line_json(15, matchers).unwrap(), "FizzBuzz");
+/// assert_eq!(line_json(7, matchers).unwrap(), "7");
+/// assert!(line_json(7, "[{").is_err());
+/// ```
+pub fn line_json(number: usize, matchers: &str) -> Result<String, serde_json::Error> {
+    let matchers: Vec<Matcher> = serde_json::from_str(matchers)?;
+    Ok(line(number, &matchers))
+}
+
+/// Provides a configurable version of FizzBuzz for a single number, that can be called from
+/// JavaScript.
+///
+/// This works the same way as `line_json()`, and is exported to JavaScript as `line`. Errors are
+/// turned into JavaScript `Error`s, so it can only be called from WebAssembly.
+#[wasm_bindgen(js_name = line)]
+pub fn line_js(number: usize, matchers: &str) -> Result<String, JsError> {
+    line_json(number, matchers).map_err(|e| JsError::new(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_json_normal() {
+        let json = serde_json::to_string(&Matcher::classic()).unwrap();
+
+        for i in 0..100 {
+            assert_eq!(line_json(i, &json).unwrap(), line(i, &Matcher::classic()));
+        }
+        assert_eq!(line_json(7, "[]").unwrap(), "7");
+    }
+
+    #[test]
+    fn line_json_invalid() {
+        assert!(line_json(3, "").is_err());
+        assert!(line_json(3, r#"{"number":3,"word":"Fizz"}"#).is_err());
+        assert!(line_json(3, r#"[{"number":0,"word":"Fizz"}]"#).is_err());
+    }
+}